@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use noir_bignum_paramgen::{
+    compute_barrett_reduction_parameter, compute_double_modulus, format_limbs,
+    split_into_120_bit_limbs,
+};
+use num_bigint::BigUint;
+
+const MODULUS_BITS: [usize; 7] = [256, 512, 1024, 2048, 4096, 8192, 16384];
+
+/**
+ * @brief a deterministic modulus with exactly `num_bits` bits
+ */
+fn modulus_of_size(num_bits: usize) -> BigUint {
+    let one = BigUint::from(1u64);
+    (one.clone() << num_bits) - (one.clone() << (num_bits / 2)) - one
+}
+
+fn bench_limbs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("split_into_120_bit_limbs");
+    for num_bits in MODULUS_BITS {
+        let modulus = modulus_of_size(num_bits);
+        group.bench_with_input(BenchmarkId::from_parameter(num_bits), &modulus, |b, m| {
+            b.iter(|| split_into_120_bit_limbs(black_box(m), num_bits))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("compute_double_modulus");
+    for num_bits in MODULUS_BITS {
+        let modulus = modulus_of_size(num_bits);
+        group.bench_with_input(BenchmarkId::from_parameter(num_bits), &modulus, |b, m| {
+            b.iter(|| compute_double_modulus(black_box(m), num_bits))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("compute_barrett_reduction_parameter");
+    for num_bits in MODULUS_BITS {
+        let modulus = modulus_of_size(num_bits);
+        group.bench_with_input(BenchmarkId::from_parameter(num_bits), &modulus, |b, m| {
+            b.iter(|| compute_barrett_reduction_parameter(black_box(m)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("format_limbs");
+    for num_bits in MODULUS_BITS {
+        let limbs = split_into_120_bit_limbs(&modulus_of_size(num_bits), num_bits);
+        group.bench_with_input(BenchmarkId::from_parameter(num_bits), &limbs, |b, l| {
+            b.iter(|| format_limbs(black_box(l)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_limbs);
+criterion_main!(benches);
@@ -0,0 +1,314 @@
+use num_bigint::BigUint;
+use rand::RngCore;
+
+use crate::{
+    check_uint_modulus, compute_bn_instance_definition, compute_instance, compute_redc_param_limbs,
+    format_limbs, instance_num_limbs, seeded_rng, split_into_120_bit_limbs, try_bignum_from_string,
+    Naming, BN_INSTANCE_IMPORTS, DEFAULT_SEED,
+};
+
+/**
+ * @brief what a batch record generates
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchMode {
+    Instance,
+    Limbs,
+    RedcParam,
+}
+
+impl BatchMode {
+    /**
+     * @brief parse a mode name. accepts the subcommand names `instance`, `limbs` and `redc-param` (or `redc_param`)
+     */
+    pub fn from_name(mode: &str) -> Result<BatchMode, String> {
+        match mode {
+            "instance" => Ok(BatchMode::Instance),
+            "limbs" => Ok(BatchMode::Limbs),
+            "redc-param" | "redc_param" => Ok(BatchMode::RedcParam),
+            _ => Err(format!(
+                "unknown mode `{mode}`, expected instance, limbs or redc-param"
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BatchMode::Instance => "instance",
+            BatchMode::Limbs => "limbs",
+            BatchMode::RedcParam => "redc-param",
+        }
+    }
+}
+
+/**
+ * @brief a single line of batch input
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchRecord {
+    pub line: usize,
+    pub name: String,
+    pub value: String,
+    pub mode: BatchMode,
+    pub uint: bool,
+}
+
+/**
+ * @brief the result of processing a batch record
+ *
+ * @param definition = noir code for the record, without imports
+ * @param limbs = the 120-bit limbs for `limbs` and `redc-param` records
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchItem {
+    pub name: String,
+    pub mode: BatchMode,
+    pub definition: String,
+    pub limbs: Option<Vec<BigUint>>,
+}
+
+impl BatchItem {
+    /**
+     * @brief the imports needed by `definition`
+     */
+    pub fn imports(&self) -> &'static str {
+        match self.mode {
+            BatchMode::Instance => BN_INSTANCE_IMPORTS,
+            BatchMode::Limbs | BatchMode::RedcParam => "",
+        }
+    }
+}
+
+/**
+ * @brief parse one line of batch input. blank lines and lines starting with `#` are skipped.
+ *        a line is either a JSON object `{"name": ..., "modulus": ..., "mode": ...}` (`value` is accepted in place
+ *        of `modulus`, `name` and `mode` are optional) or whitespace separated `VALUE [NAME [MODE]]`, where a VALUE
+ *        containing whitespace such as `"2^255 - 19"` must be quoted. records without a name are named `RECORD_{line}`.
+ *        a JSON record may set `"uint": true` or `false`, other records are uint records if `default_uint` is set
+ */
+pub fn parse_batch_record(
+    line: usize,
+    text: &str,
+    default_mode: BatchMode,
+    default_uint: bool,
+) -> Result<Option<BatchRecord>, String> {
+    let text = text.trim();
+    if text.is_empty() || text.starts_with('#') {
+        return Ok(None);
+    }
+    let (value, name, mode, uint) = if text.starts_with('{') {
+        let json: serde_json::Value =
+            serde_json::from_str(text).map_err(|error| format!("invalid JSON record: {error}"))?;
+        let field = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                None | Some(serde_json::Value::Null) => Ok(None),
+                Some(serde_json::Value::String(value)) => Ok(Some(value.clone())),
+                Some(serde_json::Value::Number(value)) => Ok(Some(value.to_string())),
+                Some(_) => Err(format!("`{key}` must be a string")),
+            }
+        };
+        let value = match field("modulus")? {
+            Some(value) => value,
+            None => field("value")?.ok_or("record has no `modulus` or `value`")?,
+        };
+        let uint = match json.get("uint") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::Bool(uint)) => Some(*uint),
+            Some(_) => return Err(String::from("`uint` must be a boolean")),
+        };
+        (value, field("name")?, field("mode")?, uint)
+    } else {
+        // a quoted value may contain whitespace, e.g. `"2^255 - 19" ED25519_Fq`
+        let (value, rest) = match text.strip_prefix('"') {
+            Some(quoted) => {
+                let (value, rest) = quoted
+                    .split_once('"')
+                    .ok_or("unterminated `\"` around VALUE")?;
+                if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+                    return Err(String::from("expected whitespace after the quoted VALUE"));
+                }
+                (value, rest)
+            }
+            None => text.split_at(text.find(char::is_whitespace).unwrap_or(text.len())),
+        };
+        let mut fields = rest.split_whitespace();
+        let name = fields.next().map(str::to_string);
+        let mode = fields.next().map(str::to_string);
+        if fields.next().is_some() {
+            return Err(String::from(
+                "expected at most three fields: VALUE [NAME [MODE]]; quote a VALUE that contains whitespace, \
+                 e.g. \"2^255 - 19\"",
+            ));
+        }
+        (value.to_string(), name, mode, None)
+    };
+    let mode = match mode {
+        Some(mode) => BatchMode::from_name(&mode)?,
+        None => default_mode,
+    };
+    Ok(Some(BatchRecord {
+        line,
+        name: name.unwrap_or_else(|| format!("RECORD_{line}")),
+        value,
+        mode,
+        uint: uint.unwrap_or(default_uint),
+    }))
+}
+
+/**
+ * @brief generate the noir code for a batch record using the same functions as the single-value commands
+ */
+pub fn process_batch_record(record: &BatchRecord) -> Result<BatchItem, String> {
+    process_batch_record_with_rng(record, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief `process_batch_record` with the base of the primality test drawn from `rng`.
+ *        the record name must satisfy `Naming::validate`, since it names noir items and output files
+ */
+pub fn process_batch_record_with_rng<R: RngCore + ?Sized>(
+    record: &BatchRecord,
+    rng: &mut R,
+) -> Result<BatchItem, String> {
+    let naming = Naming::new(&record.name);
+    naming.validate()?;
+    let value = try_bignum_from_string(&record.value)?;
+    let name = record.name.as_str();
+    let limbs_definition = |limbs: &[BigUint]| {
+        format!(
+            "pub global {}: [u128; {}] = {};\n",
+            name,
+            limbs.len(),
+            format_limbs(limbs)
+        )
+    };
+    let (definition, limbs) = match record.mode {
+        BatchMode::Instance => {
+            let (num_bits, instance) = compute_instance(&value, record.uint, rng)?;
+            (
+                compute_bn_instance_definition(num_bits, &instance, &naming),
+                None,
+            )
+        }
+        BatchMode::Limbs => {
+            let limbs = split_into_120_bit_limbs(&value, value.bits());
+            (limbs_definition(&limbs), Some(limbs))
+        }
+        BatchMode::RedcParam => {
+            if value.bits() < 2 {
+                return Err(String::from("modulus must be at least 2"));
+            }
+            // the MOD_BITS of a uint is its width, as for the instance
+            let mod_bits = if record.uint {
+                check_uint_modulus(&value)?
+            } else {
+                value.bits()
+            };
+            let limbs =
+                compute_redc_param_limbs(&value, mod_bits, instance_num_limbs(&value, mod_bits))?;
+            (limbs_definition(&limbs), Some(limbs))
+        }
+    };
+    Ok(BatchItem {
+        name: record.name.clone(),
+        mode: record.mode,
+        definition,
+        limbs,
+    })
+}
+
+#[test]
+fn test_batch_records() {
+    let plain = parse_batch_record(3, "0x3064 BN_LIMBS", BatchMode::Limbs, false)
+        .unwrap()
+        .unwrap();
+    assert_eq!(plain.name, "BN_LIMBS");
+    assert_eq!(plain.mode, BatchMode::Limbs);
+    assert_eq!(
+        process_batch_record(&plain).unwrap().definition,
+        "pub global BN_LIMBS: [u128; 1] = [0x3064];\n"
+    );
+
+    let json = parse_batch_record(
+        4,
+        r#"{"modulus": "101", "mode": "redc-param"}"#,
+        BatchMode::Instance,
+        false,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(json.name, "RECORD_4");
+    assert_eq!(json.mode, BatchMode::RedcParam);
+
+    assert_eq!(
+        parse_batch_record(5, "# comment", BatchMode::Limbs, false),
+        Ok(None)
+    );
+    assert!(parse_batch_record(6, "101 X bogus", BatchMode::Limbs, false).is_err());
+    let invalid = parse_batch_record(7, "xyz", BatchMode::Limbs, false)
+        .unwrap()
+        .unwrap();
+    assert!(process_batch_record(&invalid).is_err());
+
+    let quoted = parse_batch_record(8, r#""2^255 - 19" ED limbs"#, BatchMode::Instance, false)
+        .unwrap()
+        .unwrap();
+    assert_eq!(quoted.value, "2^255 - 19");
+    assert_eq!(quoted.name, "ED");
+    assert_eq!(quoted.mode, BatchMode::Limbs);
+    assert!(
+        parse_batch_record(9, "2^255 - 19 ED limbs", BatchMode::Limbs, false)
+            .unwrap_err()
+            .contains("quote")
+    );
+    assert!(parse_batch_record(10, r#""2^255 - 19 ED"#, BatchMode::Limbs, false).is_err());
+
+    // a redc-param record has the limbs of the instance's redc_param, with a third limb for 2^240 - 3
+    let redc_param = BatchRecord {
+        line: 11,
+        name: String::from("F_REDC"),
+        value: String::from("2^240 - 3"),
+        mode: BatchMode::RedcParam,
+        uint: false,
+    };
+    assert_eq!(
+        process_batch_record(&redc_param).unwrap().definition,
+        "pub global F_REDC: [u128; 3] = [0xc0, 0x00, 0x40];\n"
+    );
+
+    // a uint redc-param record matches the redc_param of the uint instance, 2^262 for U256
+    let u256 = parse_batch_record(
+        12,
+        r#"{"name": "U256_REDC", "modulus": "2^256", "mode": "redc-param", "uint": true}"#,
+        BatchMode::Instance,
+        false,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        process_batch_record(&u256).unwrap().definition,
+        "pub global U256_REDC: [u128; 3] = [0x00, 0x00, 0x400000];\n"
+    );
+    let u256 = parse_batch_record(13, "2^256 U256 instance", BatchMode::Limbs, true)
+        .unwrap()
+        .unwrap();
+    assert!(process_batch_record(&u256)
+        .unwrap()
+        .definition
+        .contains("BigNumParams<3, 256>"));
+    let not_uint = parse_batch_record(14, "101 F redc-param", BatchMode::Limbs, true)
+        .unwrap()
+        .unwrap();
+    assert!(process_batch_record(&not_uint).is_err());
+
+    for name in ["../escaped", "mod", "a b"] {
+        let record = BatchRecord {
+            line: 11,
+            name: String::from(name),
+            value: String::from("101"),
+            mode: BatchMode::Instance,
+            uint: false,
+        };
+        assert!(process_batch_record(&record).is_err(), "{name}");
+    }
+}
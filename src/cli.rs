@@ -0,0 +1,397 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use noir_bignum_paramgen::DEFAULT_SEED;
+
+// command line interface of the paramgen binary.
+// integer arguments are base10 unless prefixed with 0x, 0b or 0o, may contain `_` separators,
+// and may be expressions such as "2^255 - 19". `-` reads the value from stdin and `@path` reads it from a file
+#[derive(Parser)]
+#[command(
+    name = "paramgen",
+    version,
+    about = "convert a modulus into bignum instance parameters for https://github.com/noir-lang/noir-bignum",
+    after_help = "integer arguments are base10 unless prefixed with `0x`, `0b` or `0o` (e.g. \"1234\", \"0xabcd\", \"0b1010\"), \
+                  may contain `_` separators and whitespace, and may be expressions using `+`, `-`, `*`, `^` or `**` and \
+                  parentheses (e.g. \"2^255 - 19\"). `-` reads the value from stdin and `@path` reads it from a file"
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+    /// seed of the RNG that draws the bases of the Fermat primality test. the output only depends on the seed
+    #[arg(long, global = true, default_value_t = DEFAULT_SEED)]
+    pub seed: u64,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// outputs a string that represents a bignum::BigNumInstance object
+    Instance {
+        /// bignum modulus as an integer or expression, `-` for stdin or `@path` for a file
+        modulus: String,
+        /// parameter name
+        #[arg(long, default_value = "BigNumInstance")]
+        name: String,
+        /// parameter name (positional form, kept for compatibility)
+        #[arg(hide = true, conflicts_with = "name")]
+        positional_name: Option<String>,
+        /// the modulus defines an unsigned integer type rather than a field
+        #[arg(long)]
+        uint: bool,
+        /// append a noir test module with known-answer vectors
+        #[arg(long)]
+        tests: bool,
+        /// prepend the constraint-cost estimate of `cost` as comments
+        #[arg(long)]
+        cost: bool,
+        /// append the constants of the special forms detected by `special-form`
+        #[arg(long)]
+        special_form: bool,
+        /// output language of the instance
+        #[arg(long, value_enum, default_value_t = LangArg::Noir)]
+        lang: LangArg,
+        #[command(flatten)]
+        naming: NamingArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+        /// write the instance to `<DIR>/<FILE_NAME>.<EXT>`, with the extension of the output language
+        #[arg(long, value_name = "DIR", conflicts_with = "out")]
+        out_dir: Option<PathBuf>,
+    },
+    /// outputs a string that represents a bignum::BigNumInstance object for an unsigned integer of the given width
+    Uint {
+        /// integer width in bits, the modulus is 2^BITS
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        bits: usize,
+        /// parameter name, defaults to `U<BITS>`
+        #[arg(long)]
+        name: Option<String>,
+        /// append a noir test module with known-answer vectors
+        #[arg(long)]
+        tests: bool,
+        /// prepend the constraint-cost estimate of `cost` as comments
+        #[arg(long)]
+        cost: bool,
+        /// output language of the instance
+        #[arg(long, value_enum, default_value_t = LangArg::Noir)]
+        lang: LangArg,
+        #[command(flatten)]
+        naming: NamingArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+        /// write the instance to `<DIR>/<FILE_NAME>.<EXT>`, with the extension of the output language
+        #[arg(long, value_name = "DIR", conflicts_with = "out")]
+        out_dir: Option<PathBuf>,
+    },
+    /// outputs an array of Field elements that represents a BigNum object's limbs
+    Limbs {
+        /// value as an integer or expression, `-` for stdin or `@path` for a file
+        #[arg(allow_hyphen_values = true)]
+        value: String,
+        /// accept a negative value, encoded as p - |x| for a field modulus or 2^N - |x| for an N-bit uint
+        #[arg(long, requires = "target")]
+        signed: bool,
+        /// reduce values outside of the target type's range instead of failing
+        #[arg(long, requires = "target")]
+        reduce: bool,
+        /// the value is encoded for this type and padded to its limb count
+        #[command(flatten)]
+        target: TargetArgs,
+        #[command(flatten)]
+        limbs: LimbArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// outputs an array of Field elements that represents a BigNumInstance Barrett reduction parameter
+    #[command(alias = "redc_param")]
+    RedcParam {
+        /// bignum modulus as an integer or expression, `-` for stdin or `@path` for a file
+        modulus: String,
+        /// the modulus 2^N defines an N-bit unsigned integer type
+        #[arg(long)]
+        uint: bool,
+        #[command(flatten)]
+        limbs: LimbArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// decodes a noir array literal, JSON array or Prover.toml key of 120-bit limbs into an integer
+    #[command(alias = "from_limbs")]
+    FromLimbs {
+        /// limb array, `path/to/Prover.toml:key`, `-` for stdin or `@path` for a file
+        limbs: String,
+        /// additionally print the integer reduced by the modulus of this type
+        #[command(flatten)]
+        target: TargetArgs,
+        /// modulus (positional form, kept for compatibility)
+        #[arg(hide = true, conflicts_with = "target")]
+        positional_modulus: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// estimates the constraint cost of a mul and an add for a modulus
+    Cost {
+        /// bignum modulus as an integer or expression, `-` for stdin or `@path` for a file
+        modulus: String,
+        /// the modulus 2^N defines an N-bit unsigned integer type
+        #[arg(long)]
+        uint: bool,
+        /// output format of the report
+        #[arg(long, value_enum, default_value_t = CostFormat::Text)]
+        format: CostFormat,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// detects pseudo-Mersenne, Solinas and Montgomery-friendly forms of a modulus
+    SpecialForm {
+        /// bignum modulus as an integer or expression, `-` for stdin or `@path` for a file
+        modulus: String,
+        /// print the constants as noir globals with this name prefix instead of a description
+        #[arg(long)]
+        name: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// checks every BigNumParams global in the given .nr files against values recomputed from its modulus limbs
+    Lint {
+        /// noir source files
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// processes newline-delimited `VALUE [NAME [MODE]]` or JSONL `{"name", "modulus", "mode"}` records.
+    /// a VALUE containing whitespace must be quoted, e.g. `"2^255 - 19" ED25519_Fq`
+    Batch {
+        /// input file, `-` for stdin
+        #[arg(default_value = "-")]
+        input: String,
+        /// mode of records that do not specify one
+        #[arg(long, value_enum, default_value_t = BatchModeArg::Instance)]
+        mode: BatchModeArg,
+        /// the moduli of records that do not specify `uint` define unsigned integer types
+        #[arg(long)]
+        uint: bool,
+        /// combined output format
+        #[arg(long, value_enum, default_value_t = BatchFormat::Noir)]
+        format: BatchFormat,
+        #[command(flatten)]
+        output: OutputArgs,
+        /// write each record to `<DIR>/<NAME>.nr` instead of a combined output
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["out", "format"])]
+        out_dir: Option<PathBuf>,
+    },
+    /// computes reproducible test vectors for a field of the `full` catalogue, drawn with `--seed`
+    Vectors {
+        /// name of a type from the `full` catalogue, e.g. BN254_Fq or U2048
+        #[arg(long, value_name = "NAME")]
+        field: String,
+        /// number of vectors
+        #[arg(long, default_value_t = 16, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        count: usize,
+        /// output format of the vectors
+        #[arg(long, value_enum, default_value_t = Format::Noir)]
+        format: Format,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// verifies an RSA PKCS#1 v1.5 signature and writes its circuit inputs as a Prover.toml
+    RsaWitness {
+        /// RSA modulus as an integer or expression, `-` for stdin or `@path` for a file
+        #[arg(long)]
+        modulus: String,
+        /// public exponent
+        #[arg(long, default_value = "65537")]
+        exponent: String,
+        /// signature as an integer, `-` for stdin or `@path` for a file
+        #[arg(long)]
+        signature: String,
+        /// RSA width in bits the circuit's BigNumParams are generated for, the modulus must have exactly this many
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1024..=4096))]
+        bits: usize,
+        #[command(flatten)]
+        message: MessageArgs,
+        /// hash algorithm of the message
+        #[arg(long, value_enum, default_value_t = HashArg::Sha256)]
+        hash_alg: HashArg,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// verifies an ECDSA signature and writes its circuit inputs and hints as a Prover.toml
+    EcdsaWitness {
+        /// curve of the public key
+        #[arg(long, value_enum)]
+        curve: CurveArg,
+        /// x coordinate of the public key, `-` for stdin or `@path` for a file
+        #[arg(long)]
+        pubkey_x: String,
+        /// y coordinate of the public key, `-` for stdin or `@path` for a file
+        #[arg(long)]
+        pubkey_y: String,
+        /// r component of the signature
+        #[arg(long)]
+        r: String,
+        /// s component of the signature
+        #[arg(long)]
+        s: String,
+        #[command(flatten)]
+        message: MessageArgs,
+        /// hash algorithm of the message
+        #[arg(long, value_enum, default_value_t = HashArg::Sha256)]
+        hash_alg: HashArg,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// writes .nr parameter files for all currently supported BigNum fields
+    Full {
+        /// directory the parameter files are written into
+        #[arg(long, value_name = "DIR", default_value = "./fields")]
+        out_dir: PathBuf,
+        /// append a noir test module with known-answer vectors to each file
+        #[arg(long)]
+        tests: bool,
+        /// output language of the parameter files
+        #[arg(long, value_enum, default_value_t = LangArg::Noir)]
+        lang: LangArg,
+        /// convert the struct names to CamelCase, e.g. BN254_Fq to BN254Fq
+        #[arg(long)]
+        camel_case: bool,
+    },
+    /// prints a shell completion script
+    Completions { shell: Shell },
+    /// prints a roff man page
+    Man,
+}
+
+#[derive(Args)]
+pub struct OutputArgs {
+    /// write the output to FILE instead of stdout
+    #[arg(long, value_name = "FILE")]
+    pub out: Option<PathBuf>,
+}
+
+// overrides of the names an instance derives from its parameter name.
+// every name must be a legal, non-reserved noir identifier
+#[derive(Args)]
+pub struct NamingArgs {
+    /// name of the BigNum struct, defaults to the parameter name
+    #[arg(long)]
+    pub struct_name: Option<String>,
+    /// name of the BigNumParams global, defaults to `<NAME>_PARAMS`
+    #[arg(long)]
+    pub params_name: Option<String>,
+    /// file name written by `--out-dir`, without extension, defaults to the parameter name
+    #[arg(long)]
+    pub file_name: Option<String>,
+    /// name of the `--lang rust` module, defaults to the snake-cased parameter name
+    #[arg(long)]
+    pub module_name: Option<String>,
+    /// convert the default struct name to CamelCase, e.g. BN254_Fq to BN254Fq
+    #[arg(long, conflicts_with = "struct_name")]
+    pub camel_case: bool,
+}
+
+// the BigNum type a value is encoded for
+#[derive(Args)]
+#[group(id = "target", multiple = false)]
+pub struct TargetArgs {
+    /// modulus of the target field type
+    #[arg(long)]
+    pub modulus: Option<String>,
+    /// width in bits of the target uint type
+    #[arg(long, value_name = "BITS")]
+    pub uint: Option<usize>,
+    /// name of a target type from the `full` catalogue, e.g. BN254_Fq or U2048
+    #[arg(long, value_name = "NAME")]
+    pub params: Option<String>,
+}
+
+// the signed message, either hashed already or hashed with `--hash-alg`
+#[derive(Args)]
+#[group(id = "signed_message", required = true, multiple = false)]
+pub struct MessageArgs {
+    /// hex digest of the message
+    #[arg(long, value_name = "HEX")]
+    pub hash: Option<String>,
+    /// message text
+    #[arg(long, value_name = "TEXT")]
+    pub message: Option<String>,
+    /// file containing the message
+    #[arg(long, value_name = "PATH")]
+    pub message_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct LimbArgs {
+    /// number of bits per limb
+    #[arg(long, default_value_t = 120, value_parser = clap::value_parser!(u64).range(1..))]
+    pub limb_bits: u64,
+    /// pad the output to exactly N limbs, failing if the value does not fit
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub num_limbs: Option<usize>,
+    /// output format of the limb array
+    #[arg(long, value_enum, default_value_t = Format::Noir)]
+    pub format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum BatchModeArg {
+    Instance,
+    Limbs,
+    RedcParam,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum BatchFormat {
+    /// a single noir module containing every record
+    Noir,
+    /// one JSON object per record
+    Jsonl,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CostFormat {
+    /// one item per line
+    Text,
+    /// a JSON object
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum LangArg {
+    /// a noir BigNumParams global
+    Noir,
+    /// a rust module of u128 limb constants
+    Rust,
+    /// a typescript module of bigint limb constants with limb conversion helpers
+    Ts,
+    /// a solidity library of uint256 limb constants with limb packing functions
+    Solidity,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum HashArg {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CurveArg {
+    Secp256k1,
+    /// also known as P-256 or prime256v1
+    Secp256r1,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// noir array literal
+    Noir,
+    /// JSON array of hex strings
+    Json,
+}
+
+#[test]
+fn test_cli() {
+    use clap::CommandFactory;
+    Cli::command().debug_assert();
+}
@@ -0,0 +1,188 @@
+use crate::prelude::*;
+
+/**
+ * @brief constraint-cost estimate of the arithmetic of a BigNumParams<N, MOD_BITS> instance.
+ *        the model follows noir-bignum's algorithms. a multiplication r = a * b mod p computes the quotient q and
+ *        remainder r out of circuit, range checks the N limbs of q and r, and checks a * b - q * p - r = 0
+ *        column by column over the 2N - 1 limb columns of the product, carrying between columns.
+ *        an addition r = a + b mod p computes a borrow flag out of circuit, range checks the N limbs of r
+ *        and checks a + b - flag * p - r = 0 limb by limb with boolean carries.
+ *        gate estimates assume a width-4 arithmetization (as in barretenberg's UltraPlonk):
+ *        one gate per witness * witness product, three linear terms per gate,
+ *        and range checks decomposed into 14-bit lookups, i.e. ceil(bits / 14) gates per range check
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostReport {
+    pub mod_bits: usize,
+    pub num_limbs: usize,
+    pub product_columns: usize,
+    pub product_column_bits: usize,
+    pub carry_bits: usize,
+    pub mul_limb_range_checks: usize,
+    pub mul_carry_range_checks: usize,
+    pub mul_witness_products: usize,
+    pub mul_constant_products: usize,
+    pub mul_gates: usize,
+    pub add_limb_range_checks: usize,
+    pub add_carry_range_checks: usize,
+    pub add_gates: usize,
+}
+
+const LIMB_BITS: usize = 120;
+const LOOKUP_BITS: usize = 14;
+
+fn range_check_gates(bits: usize) -> usize {
+    bits.div_ceil(LOOKUP_BITS)
+}
+
+fn ceil_log2(x: usize) -> usize {
+    x.next_power_of_two().trailing_zeros() as usize
+}
+
+/**
+ * @brief estimate the cost of a multiplication and an addition for a modulus of `mod_bits` bits held in `num_limbs`
+ *        limbs, the N of the BigNumParams (see `instance_num_limbs`), which may exceed ceil(mod_bits / 120)
+ */
+pub fn compute_cost_report(mod_bits: usize, num_limbs: usize) -> CostReport {
+    let num_limbs = num_limbs.max(1);
+    let product_columns = 2 * num_limbs - 1;
+    // a column sums up to N products of two 120-bit limbs for a * b and for q * p, with opposite signs
+    let product_column_bits = 2 * LIMB_BITS + ceil_log2(num_limbs) + 1;
+    let carry_bits = product_column_bits - LIMB_BITS + 1;
+
+    let mul_limb_range_checks = 2 * num_limbs;
+    let mul_carry_range_checks = product_columns - 1;
+    let mul_witness_products = num_limbs * num_limbs;
+    let mul_constant_products = num_limbs * num_limbs;
+    // q * p terms, r limbs, and each carry enters two columns
+    let mul_linear_terms = mul_constant_products + num_limbs + 2 * mul_carry_range_checks;
+    let mul_gates = mul_witness_products
+        + mul_linear_terms.div_ceil(3)
+        + mul_limb_range_checks * range_check_gates(LIMB_BITS)
+        + mul_carry_range_checks * range_check_gates(carry_bits);
+
+    let add_limb_range_checks = num_limbs;
+    let add_carry_range_checks = num_limbs - 1;
+    // per limb: a, b, flag * p, r and the carries, plus the boolean borrow flag
+    let add_linear_terms = 4 * num_limbs + 2 * add_carry_range_checks;
+    let add_gates = add_linear_terms.div_ceil(3)
+        + add_limb_range_checks * range_check_gates(LIMB_BITS)
+        + add_carry_range_checks
+        + 1;
+
+    CostReport {
+        mod_bits,
+        num_limbs,
+        product_columns,
+        product_column_bits,
+        carry_bits,
+        mul_limb_range_checks,
+        mul_carry_range_checks,
+        mul_witness_products,
+        mul_constant_products,
+        mul_gates,
+        add_limb_range_checks,
+        add_carry_range_checks,
+        add_gates,
+    }
+}
+
+impl CostReport {
+    /**
+     * @brief human readable report, one item per line
+     */
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!(
+                "MOD_BITS = {}, {} limbs of {LIMB_BITS} bits",
+                self.mod_bits, self.num_limbs
+            ),
+            String::from("mul (a * b mod p):"),
+            format!(
+                "  product: {} columns of up to {} bits",
+                self.product_columns, self.product_column_bits
+            ),
+            format!(
+                "  range checks: {} x {LIMB_BITS}-bit (quotient and remainder limbs), {} x {}-bit (carries)",
+                self.mul_limb_range_checks, self.mul_carry_range_checks, self.carry_bits
+            ),
+            format!(
+                "  multiplications: {} witness x witness, {} witness x constant",
+                self.mul_witness_products, self.mul_constant_products
+            ),
+            format!("  estimated gates: {}", self.mul_gates),
+            String::from("add (a + b mod p):"),
+            format!(
+                "  range checks: {} x {LIMB_BITS}-bit (result limbs), {} x 1-bit (carries)",
+                self.add_limb_range_checks, self.add_carry_range_checks
+            ),
+            format!("  estimated gates: {}", self.add_gates),
+            String::from("a native Field mul or add is 1 gate"),
+        ]
+    }
+
+    /**
+     * @brief the report as noir line comments
+     */
+    pub fn to_comment(&self) -> String {
+        let mut r = String::new();
+        for line in self.lines() {
+            r += "// ";
+            r += &line;
+            r += "\n";
+        }
+        r
+    }
+
+    /**
+     * @brief the report as a JSON object
+     */
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "mod_bits": self.mod_bits,
+            "num_limbs": self.num_limbs,
+            "limb_bits": LIMB_BITS,
+            "mul": {
+                "product_columns": self.product_columns,
+                "product_column_bits": self.product_column_bits,
+                "carry_bits": self.carry_bits,
+                "limb_range_checks": self.mul_limb_range_checks,
+                "carry_range_checks": self.mul_carry_range_checks,
+                "witness_products": self.mul_witness_products,
+                "constant_products": self.mul_constant_products,
+                "estimated_gates": self.mul_gates,
+            },
+            "add": {
+                "limb_range_checks": self.add_limb_range_checks,
+                "carry_range_checks": self.add_carry_range_checks,
+                "estimated_gates": self.add_gates,
+            },
+        })
+    }
+}
+
+#[test]
+fn test_cost_report() {
+    let bn254 = compute_cost_report(254, 3);
+    assert_eq!(bn254.num_limbs, 3);
+    assert_eq!(bn254.product_columns, 5);
+    assert_eq!(bn254.product_column_bits, 243);
+    assert_eq!(bn254.carry_bits, 124);
+    assert_eq!(bn254.mul_limb_range_checks, 6);
+    assert_eq!(bn254.mul_carry_range_checks, 4);
+    // 9 products + ceil((9 + 3 + 8) / 3) linear gates + 6 * 9 + 4 * 9 range check gates
+    assert_eq!(bn254.mul_gates, 9 + 7 + 54 + 36);
+    // ceil((12 + 4) / 3) linear gates + 3 * 9 range check gates + 2 carries + 1 flag
+    assert_eq!(bn254.add_gates, 6 + 27 + 2 + 1);
+
+    let small = compute_cost_report(64, 1);
+    assert_eq!(small.num_limbs, 1);
+    assert_eq!(small.mul_carry_range_checks, 0);
+    assert!(compute_cost_report(2048, 18).mul_gates > bn254.mul_gates);
+
+    // a 240-bit uint has a third limb for its redc parameter, and pays for it
+    let u240 = compute_cost_report(240, crate::uint_num_limbs(240));
+    assert_eq!(u240.num_limbs, 3);
+    assert_eq!(u240.mul_gates, bn254.mul_gates);
+    assert!(u240.lines().iter().any(|line| line.contains("3 limbs")));
+}
@@ -0,0 +1,101 @@
+use num_bigint::BigUint;
+use num_traits::{Num, Zero};
+
+use crate::prelude::*;
+
+/**
+ * @brief parse a single limb. accepts base10, or base16 when prepended with "0x".
+ *        surrounding quotes (as found in JSON arrays and Prover.toml files) are stripped
+ */
+fn limb_from_str(limb_str: &str) -> Result<BigUint, String> {
+    let trimmed = limb_str.trim().trim_matches('"').trim();
+    let parsed = match trimmed.strip_prefix("0x") {
+        Some(hex_str) => BigUint::from_str_radix(hex_str, 16),
+        None => BigUint::from_str_radix(trimmed, 10),
+    };
+    parsed.map_err(|error| format!("could not parse limb `{trimmed}`: {error:?}"))
+}
+
+/**
+ * @brief parse an array of limbs, either a noir array literal (`[0x01, 0x02]`) or a JSON array
+ *        (`["0x01", "0x02"]`, `[1, 2]`). a trailing comma is accepted
+ */
+pub fn limbs_from_str(array_str: &str) -> Result<Vec<BigUint>, String> {
+    let trimmed = array_str.trim();
+    let inner = trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(|| {
+            format!("expected an array of limbs enclosed in `[` and `]`, got `{trimmed}`")
+        })?;
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|limb| !limb.is_empty())
+        .map(limb_from_str)
+        .collect()
+}
+
+/**
+ * @brief find the limb array assigned to `key` in the contents of a Prover.toml file.
+ *        supports both `key = [...]` and a `[key]` table containing `limbs = [...]`.
+ *        arrays may span multiple lines
+ */
+pub fn limbs_from_prover_toml(toml_str: &str, key: &str) -> Result<Vec<BigUint>, String> {
+    let mut in_table = false;
+    let mut array_start: Option<usize> = None;
+    let mut offset = 0;
+    for line in toml_str.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && !trimmed.contains('=') {
+            in_table = trimmed.trim_matches(|c| c == '[' || c == ']').trim() == key;
+        } else if let Some((lhs, _)) = trimmed.split_once('=') {
+            let lhs = lhs.trim().trim_matches('"');
+            if lhs == key || (in_table && lhs == "limbs") {
+                array_start = Some(offset + line.find('=').unwrap() + 1);
+                break;
+            }
+        }
+        offset += line.len();
+    }
+    let start = array_start.ok_or_else(|| format!("key `{key}` not found in Prover.toml"))?;
+    let rest = &toml_str[start..];
+    let end = rest
+        .find(']')
+        .ok_or_else(|| format!("unterminated array for key `{key}`"))?;
+    limbs_from_str(&rest[..=end])
+}
+
+/**
+ * @brief reconstruct an integer from little-endian 120-bit limbs.
+ *        errors if any limb is not less than 2^120
+ */
+pub fn integer_from_limbs(limbs: &[BigUint]) -> Result<BigUint, String> {
+    let mut result = BigUint::zero();
+    for (i, limb) in limbs.iter().enumerate().rev() {
+        if limb.bits() > 120 {
+            return Err(format!(
+                "limb {i} (0x{}) does not fit in 120 bits",
+                limb.to_str_radix(16)
+            ));
+        }
+        result = (result << 120usize) + limb;
+    }
+    Ok(result)
+}
+
+#[test]
+fn test_integer_from_limbs() {
+    let noir = limbs_from_str("[0x890abcdef1234567890abcdef12345, 0x01234567]").unwrap();
+    let json = limbs_from_str("[\"0x890abcdef1234567890abcdef12345\", \"19088743\",]").unwrap();
+    let expected = BigUint::from_str_radix("1234567890abcdef1234567890abcdef12345", 16).unwrap();
+    assert_eq!(integer_from_limbs(&noir).unwrap(), expected);
+    assert_eq!(integer_from_limbs(&json).unwrap(), expected);
+
+    let toml = "x = 1\n[signature]\nlimbs = [\n  \"0x890abcdef1234567890abcdef12345\",\n  \"0x01234567\"\n]\n";
+    let from_toml = limbs_from_prover_toml(toml, "signature").unwrap();
+    assert_eq!(integer_from_limbs(&from_toml).unwrap(), expected);
+
+    let oversized = limbs_from_str("[0x01000000000000000000000000000000]").unwrap();
+    assert!(integer_from_limbs(&oversized).is_err());
+}
@@ -0,0 +1,320 @@
+use num_bigint::{BigUint, ModInverse};
+use num_traits::Zero;
+
+use crate::prelude::*;
+use crate::{format_limbs_json, split_into_exact_limbs};
+
+/**
+ * @brief a short Weierstrass curve y^2 = x^3 + ax + b with a prime order group, as used by ECDSA
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcdsaCurve {
+    Secp256k1,
+    Secp256r1,
+}
+
+/**
+ * @brief the domain parameters of a curve, from SEC 2
+ */
+struct CurveParams {
+    p: BigUint,
+    n: BigUint,
+    a: BigUint,
+    b: BigUint,
+    generator: (BigUint, BigUint),
+}
+
+/**
+ * @brief an affine point, `None` being the point at infinity
+ */
+type Point = Option<(BigUint, BigUint)>;
+
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).expect("curve constants are hex")
+}
+
+impl EcdsaCurve {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EcdsaCurve::Secp256k1 => "secp256k1",
+            EcdsaCurve::Secp256r1 => "secp256r1",
+        }
+    }
+
+    /**
+     * @brief name of the generated instance of the base field, which pubkey coordinates are elements of
+     */
+    pub fn base_field_name(&self) -> &'static str {
+        match self {
+            EcdsaCurve::Secp256k1 => "Secp256k1_Fq",
+            EcdsaCurve::Secp256r1 => "Secp256r1_Fq",
+        }
+    }
+
+    /**
+     * @brief name of the generated instance of the scalar field, which r, s and the hints are elements of
+     */
+    pub fn scalar_field_name(&self) -> &'static str {
+        match self {
+            EcdsaCurve::Secp256k1 => "Secp256k1_Fr",
+            EcdsaCurve::Secp256r1 => "Secp256r1_Fr",
+        }
+    }
+
+    fn params(&self) -> CurveParams {
+        match self {
+            EcdsaCurve::Secp256k1 => CurveParams {
+                p: from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+                n: from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+                a: BigUint::zero(),
+                b: BigUint::from(7u64),
+                generator: (
+                    from_hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+                    from_hex("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"),
+                ),
+            },
+            EcdsaCurve::Secp256r1 => CurveParams {
+                p: from_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+                n: from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+                a: from_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc"),
+                b: from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"),
+                generator: (
+                    from_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+                    from_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
+                ),
+            },
+        }
+    }
+}
+
+/**
+ * @brief inverse of a nonzero value modulo a prime
+ */
+fn invert(value: &BigUint, modulus: &BigUint) -> BigUint {
+    value
+        .clone()
+        .mod_inverse(modulus)
+        .and_then(|inverse| inverse.to_biguint())
+        .expect("nonzero values are invertible modulo a prime")
+}
+
+impl CurveParams {
+    fn sub(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        (x + &self.p - y) % &self.p
+    }
+
+    fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        let p = &self.p;
+        x < p && y < p && (y * y) % p == (x * x * x + &self.a * x + &self.b) % p
+    }
+
+    fn double(&self, point: &Point) -> Point {
+        let (x, y) = point.as_ref()?;
+        if y.is_zero() {
+            return None;
+        }
+        let p = &self.p;
+        let lambda = ((BigUint::from(3u64) * x * x + &self.a) * invert(&((y << 1) % p), p)) % p;
+        let x3 = self.sub(&((&lambda * &lambda) % p), &((x << 1) % p));
+        let y3 = self.sub(&((&lambda * self.sub(x, &x3)) % p), y);
+        Some((x3, y3))
+    }
+
+    fn add(&self, lhs: &Point, rhs: &Point) -> Point {
+        let Some((x1, y1)) = lhs else {
+            return rhs.clone();
+        };
+        let Some((x2, y2)) = rhs else {
+            return lhs.clone();
+        };
+        if x1 == x2 {
+            return if y1 == y2 { self.double(lhs) } else { None };
+        }
+        let p = &self.p;
+        let lambda = (self.sub(y2, y1) * invert(&self.sub(x2, x1), p)) % p;
+        let x3 = self.sub(&self.sub(&((&lambda * &lambda) % p), x1), x2);
+        let y3 = self.sub(&((&lambda * self.sub(x1, &x3)) % p), y1);
+        Some((x3, y3))
+    }
+
+    /**
+     * @brief scalar multiplication by double-and-add. not constant time, which is fine for public values
+     */
+    fn mul(&self, scalar: &BigUint, point: &Point) -> Point {
+        let mut r = None;
+        for byte in scalar.to_bytes_be() {
+            for i in (0..8).rev() {
+                r = self.double(&r);
+                if (byte >> i) & 1 == 1 {
+                    r = self.add(&r, point);
+                }
+            }
+        }
+        r
+    }
+}
+
+/**
+ * @brief the inputs of a circuit that verifies an ECDSA signature, checked by `ecdsa_witness`.
+ *        `z` is the message hash as an integer modulo the group order, and `s_inv`, `u1 = z * s_inv` and
+ *        `u2 = r * s_inv` are the hints unconstrained code would otherwise compute
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcdsaWitness {
+    pub curve: EcdsaCurve,
+    pub pubkey_x: BigUint,
+    pub pubkey_y: BigUint,
+    pub r: BigUint,
+    pub s: BigUint,
+    pub digest: Vec<u8>,
+    pub z: BigUint,
+    pub s_inv: BigUint,
+    pub u1: BigUint,
+    pub u2: BigUint,
+}
+
+/**
+ * @brief verify an ECDSA signature of a digest (SEC 1 section 4.1.4) and collect the inputs of a circuit that
+ *        verifies it. a digest longer than the group order is truncated to its leftmost bits
+ */
+pub fn ecdsa_witness(
+    curve: EcdsaCurve,
+    pubkey_x: &BigUint,
+    pubkey_y: &BigUint,
+    r: &BigUint,
+    s: &BigUint,
+    digest: &[u8],
+) -> Result<EcdsaWitness, String> {
+    let params = curve.params();
+    let n = &params.n;
+    if !params.is_on_curve(pubkey_x, pubkey_y) {
+        return Err(format!("the public key is not a point of {}", curve.name()));
+    }
+    for (name, value) in [("r", r), ("s", s)] {
+        if value.is_zero() || value >= n {
+            return Err(format!(
+                "{name} must be between 1 and the group order of {} minus 1",
+                curve.name()
+            ));
+        }
+    }
+    if digest.is_empty() {
+        return Err(String::from("the message hash is empty"));
+    }
+    let mut z = BigUint::from_bytes_be(digest);
+    let digest_bits = 8 * digest.len();
+    if digest_bits > n.bits() {
+        z >>= digest_bits - n.bits();
+    }
+    let z = z % n;
+    let s_inv = invert(s, n);
+    let u1 = (&z * &s_inv) % n;
+    let u2 = (r * &s_inv) % n;
+    let generator = Some(params.generator.clone());
+    let pubkey = Some((pubkey_x.clone(), pubkey_y.clone()));
+    let verified = params
+        .add(&params.mul(&u1, &generator), &params.mul(&u2, &pubkey))
+        .is_some_and(|(x, _)| &(x % n) == r);
+    if !verified {
+        return Err(format!(
+            "signature verification failed: the x coordinate of u1 * G + u2 * Q is not r on {}",
+            curve.name()
+        ));
+    }
+    Ok(EcdsaWitness {
+        curve,
+        pubkey_x: pubkey_x.clone(),
+        pubkey_y: pubkey_y.clone(),
+        r: r.clone(),
+        s: s.clone(),
+        digest: digest.to_vec(),
+        z,
+        s_inv,
+        u1,
+        u2,
+    })
+}
+
+impl EcdsaWitness {
+    /**
+     * @brief a Prover.toml with the 120-bit limbs of the pubkey coordinates, r, s, z and the hints as hex strings,
+     *        sized for the generated base and scalar field instances of the curve, and the digest bytes
+     */
+    pub fn to_prover_toml(&self) -> String {
+        let params = self.curve.params();
+        let limbs = |value: &BigUint, modulus: &BigUint| {
+            format_limbs_json(
+                &split_into_exact_limbs(value, modulus.bits().div_ceil(120), 120)
+                    .expect("ecdsa_witness checked that every value is reduced"),
+            )
+        };
+        let digest: Vec<String> = self.digest.iter().map(|byte| byte.to_string()).collect();
+        let mut r = format!(
+            "# {} ECDSA signature, verified by paramgen ecdsa-witness\n",
+            self.curve.name()
+        );
+        r += &format!(
+            "# pubkey_x and pubkey_y are {} values, the others {} values, in limbs of 120 bits, least significant first\n",
+            self.curve.base_field_name(),
+            self.curve.scalar_field_name()
+        );
+        r += &format!("pubkey_x = {}\n", limbs(&self.pubkey_x, &params.p));
+        r += &format!("pubkey_y = {}\n", limbs(&self.pubkey_y, &params.p));
+        for (key, value) in [
+            ("r", &self.r),
+            ("s", &self.s),
+            ("z", &self.z),
+            ("s_inv", &self.s_inv),
+            ("u1", &self.u1),
+            ("u2", &self.u2),
+        ] {
+            r += &format!("{key} = {}\n", limbs(value, &params.n));
+        }
+        r += &format!("message_hash = [{}]\n", digest.join(", "));
+        r
+    }
+}
+
+#[test]
+fn test_ecdsa_witness() {
+    use crate::HashAlgorithm;
+    use num_traits::One;
+
+    let parse = |s: &str| crate::try_bignum_from_string(s).unwrap();
+    // keys and signatures of "hello noir" made with `openssl dgst -sha256 -sign`
+    let digest = HashAlgorithm::Sha256.digest(b"hello noir");
+    for (curve, x, y, r, s) in [
+        (
+            EcdsaCurve::Secp256k1,
+            "0x2b65034858752713daa60e1873dc8fae286e6749b908378fa984f7649580bdeb",
+            "0xe6ca24d999197cf4068bf255b63f525b3fcff32b20dcee774dc14b6a2ffe6e6c",
+            "0xe706a8cbd0e78303019e730c0b1ac9c1aaaa9d4f7e0730665f169798402fbbbb",
+            "0x03e39d84f690400bd6fb47d588ee04078a890a4f4c80ba815ac8eb4fb4a37dc8",
+        ),
+        (
+            EcdsaCurve::Secp256r1,
+            "0xde3037cfd29f93b55c553a48cc72f25dd4ee979d399dbe6ec04984a388c098e2",
+            "0x9b5564cabbae917902a27094bceabc0f6670ca200f03f693913f87f0ff997b22",
+            "0x5609b5e7b09e769a43f2bf2756062040419f06d5c52d5eba37a1f8f40772d1a2",
+            "0x9d6dad17d6cb27ad2dcf9879ff545aa4cb38cfd1e033d363d032579ef78b3287",
+        ),
+    ] {
+        let params = curve.params();
+        assert!(params.is_on_curve(&params.generator.0, &params.generator.1));
+        assert!(params
+            .mul(&params.n, &Some(params.generator.clone()))
+            .is_none());
+
+        let (x, y, r, s) = (parse(x), parse(y), parse(r), parse(s));
+        let witness = ecdsa_witness(curve, &x, &y, &r, &s, &digest).unwrap();
+        assert_eq!((&witness.s * &witness.s_inv) % &params.n, BigUint::one());
+        let toml = witness.to_prover_toml();
+        let limbs = crate::limbs_from_prover_toml(&toml, "u2").unwrap();
+        assert_eq!(limbs.len(), 3);
+        assert_eq!(crate::integer_from_limbs(&limbs).unwrap(), witness.u2);
+
+        let tampered = HashAlgorithm::Sha256.digest(b"hello noir!");
+        assert!(ecdsa_witness(curve, &x, &y, &r, &s, &tampered).is_err());
+        assert!(ecdsa_witness(curve, &x, &(&y + 1u64), &r, &s, &digest).is_err());
+    }
+}
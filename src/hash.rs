@@ -0,0 +1,84 @@
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::prelude::*;
+
+/**
+ * @brief a hash algorithm of the message of a signature
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha384 => "SHA-384",
+            HashAlgorithm::Sha512 => "SHA-512",
+        }
+    }
+
+    /**
+     * @brief length of a digest, in bytes
+     */
+    pub fn output_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 => 64,
+        }
+    }
+
+    pub fn digest(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest(message).to_vec(),
+            HashAlgorithm::Sha384 => Sha384::digest(message).to_vec(),
+            HashAlgorithm::Sha512 => Sha512::digest(message).to_vec(),
+        }
+    }
+}
+
+/**
+ * @brief parse a hex digest, with or without a `0x` prefix, and check its length against the hash algorithm
+ */
+pub fn parse_digest(hex: &str, hash_algorithm: HashAlgorithm) -> Result<Vec<u8>, String> {
+    let hex = hex.trim();
+    let digits = hex
+        .strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex);
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(format!("invalid hex digest `{hex}`"));
+    }
+    if digits.len() != 2 * hash_algorithm.output_len() {
+        return Err(format!(
+            "a {} digest is {} hex digits, got {}",
+            hash_algorithm.name(),
+            2 * hash_algorithm.output_len(),
+            digits.len()
+        ));
+    }
+    Ok((0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).expect("digits are hex"))
+        .collect())
+}
+
+#[test]
+fn test_digest() {
+    let digest = HashAlgorithm::Sha256.digest(b"abc");
+    assert_eq!(
+        parse_digest(
+            "0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            HashAlgorithm::Sha256
+        )
+        .unwrap(),
+        digest
+    );
+    assert_eq!(HashAlgorithm::Sha512.digest(b"abc").len(), 64);
+    assert!(parse_digest("ba78", HashAlgorithm::Sha256).is_err());
+    assert!(parse_digest(&"zz".repeat(32), HashAlgorithm::Sha256).is_err());
+}
@@ -1,7 +1,63 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 // use base64ct::{Base64, Encoding};
-use num_bigint::{BigUint, RandomBits};
-use num_traits::Num;
-use rand::Rng;
+extern crate alloc;
+
+use core::fmt;
+use num_bigint::{BigInt, BigUint, RandBigInt, Sign};
+use num_traits::{One, Signed, Zero};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::prelude::*;
+
+/**
+ * @brief the alloc types and macros that the std prelude provides, so that every module also builds without std
+ */
+mod prelude {
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+}
+
+#[cfg(feature = "std")]
+mod batch;
+mod cost;
+mod decode;
+mod ecdsa;
+mod hash;
+#[cfg(feature = "std")]
+mod lint;
+mod naming;
+mod noir_tests;
+mod parse;
+mod render;
+mod rsa;
+mod special_form;
+mod vectors;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "std")]
+pub use batch::{
+    parse_batch_record, process_batch_record, process_batch_record_with_rng, BatchItem, BatchMode,
+    BatchRecord,
+};
+pub use cost::{compute_cost_report, CostReport};
+pub use decode::{integer_from_limbs, limbs_from_prover_toml, limbs_from_str};
+pub use ecdsa::{ecdsa_witness, EcdsaCurve, EcdsaWitness};
+pub use hash::{parse_digest, HashAlgorithm};
+#[cfg(feature = "std")]
+pub use lint::{lint_bignum_params, lint_bignum_params_with_rng, LintIssue};
+pub use naming::{check_noir_identifier, Naming};
+pub use parse::evaluate_integer_expression;
+pub use render::Lang;
+pub use rsa::{pkcs1_v15_encode, rsa_witness, RsaWitness, RSA_MAX_BITS, RSA_MIN_BITS};
+pub use special_form::{
+    detect_special_forms, format_solinas_terms, MontgomeryFriendly, PseudoMersenne, SolinasTerm,
+    SpecialForms,
+};
+pub use vectors::{test_vectors, test_vectors_with_rng, TestVector, TestVectors};
 
 /**
  * @brief mirrors the noir BigNumInstance object, where each noir Field element is a BigUint element
@@ -21,9 +77,16 @@ const BARRETT_REDUCTION_OVERFLOW_BITS: usize = 6;
  *                            modulus
  */
 pub fn compute_barrett_reduction_parameter(modulus: &BigUint) -> BigUint {
-    let k = modulus.bits();
-    let multiplicand =
-        BigUint::new([1].to_vec()) << (k as usize * 2 + BARRETT_REDUCTION_OVERFLOW_BITS);
+    compute_barrett_reduction_parameter_with_bits(modulus, modulus.bits())
+}
+
+/**
+ * @brief compute the barrett reduction parameter for a declared MOD_BITS value k, i.e. 2^(2k + 6) / modulus.
+ *        this differs from `compute_barrett_reduction_parameter` for unsigned integer types,
+ *        where MOD_BITS is the integer width and the modulus 2^k has k + 1 bits
+ */
+pub fn compute_barrett_reduction_parameter_with_bits(modulus: &BigUint, k: usize) -> BigUint {
+    let multiplicand = BigUint::new([1].to_vec()) << (k * 2 + BARRETT_REDUCTION_OVERFLOW_BITS);
     let barrett_reduction_parameter: BigUint = (multiplicand) / modulus;
 
     //    let x: &str = "0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
@@ -58,179 +121,584 @@ pub fn compute_barrett_reduction_parameter(modulus: &BigUint) -> BigUint {
  * @brief split a BigUint into a vector of 120-bit slices
  */
 pub fn split_into_120_bit_limbs(_input: &BigUint, num_bits: usize) -> Vec<BigUint> {
-    let num_limbs: usize = (num_bits / 120) + (num_bits % 120 != 0) as usize;
-    let mut input = _input.clone();
-    let one: BigUint = BigUint::from(1 as u64);
-    let mask: BigUint = (one.clone() << 120 as usize) - one.clone();
-
-    let mut r: Vec<BigUint> = Vec::new();
-    for _ in 0..num_limbs {
-        let slice = input.clone() & mask.clone();
-        input = input.clone() >> 120 as usize;
-        r.push(slice);
+    split_into_limbs(_input, num_bits, 120)
+}
+
+/**
+ * @brief split a BigUint into a vector of `limb_bits`-bit slices, enough to cover `num_bits` bits.
+ *        the output is padded with zero limbs to ceil(num_bits / limb_bits) limbs, and is never truncated:
+ *        an input wider than `num_bits` gets the extra limbs it needs. there is always at least one limb.
+ *        limbs are read directly from the little-endian bytes of the input, so the cost is linear in its size
+ */
+pub fn split_into_limbs(_input: &BigUint, num_bits: usize, limb_bits: usize) -> Vec<BigUint> {
+    let num_limbs: usize = num_bits.max(_input.bits()).div_ceil(limb_bits).max(1);
+    read_limbs(_input, num_limbs, limb_bits)
+}
+
+/**
+ * @brief split a BigUint into exactly `num_limbs` limbs of `limb_bits` bits, padded with zero limbs.
+ *        errors if the input does not fit
+ */
+pub fn split_into_exact_limbs(
+    input: &BigUint,
+    num_limbs: usize,
+    limb_bits: usize,
+) -> Result<Vec<BigUint>, String> {
+    if input.bits() > num_limbs * limb_bits {
+        return Err(format!(
+            "0x{} has {} bits and does not fit in {num_limbs} limbs of {limb_bits} bits",
+            input.to_str_radix(16),
+            input.bits()
+        ));
+    }
+    Ok(read_limbs(input, num_limbs, limb_bits))
+}
+
+fn read_limbs(input: &BigUint, num_limbs: usize, limb_bits: usize) -> Vec<BigUint> {
+    let bytes = input.to_bytes_le();
+    let mut scratch: Vec<u8> = Vec::with_capacity(limb_bits.div_ceil(8));
+
+    let mut r: Vec<BigUint> = Vec::with_capacity(num_limbs);
+    for i in 0..num_limbs {
+        read_bits_le(&bytes, i * limb_bits, limb_bits, &mut scratch);
+        r.push(BigUint::from_bytes_le(&scratch));
     }
     r
 }
 
 /**
- * @brief given a modulus BigUint, compute a BNInstance object
+ * @brief copy `width` bits starting at bit `offset` of a little-endian byte string into `out`.
+ *        bits past the end of `bytes` are zero
  */
-fn compute_bn_instance_parameters(modulus: &BigUint, num_bits: usize) -> BNInstance {
-    let modulus_limbs = split_into_120_bit_limbs(&modulus, num_bits);
-    let double_modulus = compute_double_modulus(&modulus, num_bits);
-    let has_multiplicative_inverse = is_prime(&modulus);
-    let redc_param =
-        split_into_120_bit_limbs(&compute_barrett_reduction_parameter(&modulus), num_bits);
-    BNInstance {
+fn read_bits_le(bytes: &[u8], offset: usize, width: usize, out: &mut Vec<u8>) {
+    let start = offset / 8;
+    let shift = offset % 8;
+    let byte_at = |i: usize| bytes.get(i).copied().unwrap_or(0);
+    out.clear();
+    for i in start..start + width.div_ceil(8) {
+        let mut byte = byte_at(i) >> shift;
+        if shift > 0 {
+            byte |= byte_at(i + 1) << (8 - shift);
+        }
+        out.push(byte);
+    }
+    let excess_bits = out.len() * 8 - width;
+    if let Some(last) = out.last_mut() {
+        *last &= 0xff >> excess_bits;
+    }
+}
+
+/**
+ * @brief given a modulus BigUint, compute a BNInstance object with `instance_num_limbs` limbs.
+ *        `rng` draws the base of the primality test
+ */
+fn compute_bn_instance_parameters<R: RngCore + ?Sized>(
+    modulus: &BigUint,
+    num_bits: usize,
+    rng: &mut R,
+) -> Result<BNInstance, String> {
+    let num_limbs = instance_num_limbs(modulus, num_bits);
+    let redc_param = compute_redc_param_limbs(modulus, num_bits, num_limbs)?;
+    let modulus_limbs = split_into_exact_limbs(modulus, num_limbs, 120)?;
+    let double_modulus = compute_double_modulus_limbs(modulus, num_limbs);
+    let has_multiplicative_inverse = is_prime_with_rng(modulus, rng);
+    Ok(BNInstance {
         has_multiplicative_inverse,
         modulus: modulus_limbs,
         double_modulus,
         redc_param,
+    })
+}
+
+/**
+ * @brief the redc_param limbs of BigNumParams<num_limbs, mod_bits>, i.e. of 2^(2 * mod_bits + 6) / modulus.
+ *        errors if the parameter does not fit in `num_limbs` limbs, which cannot happen for `instance_num_limbs`
+ */
+pub fn compute_redc_param_limbs(
+    modulus: &BigUint,
+    mod_bits: usize,
+    num_limbs: usize,
+) -> Result<Vec<BigUint>, String> {
+    let redc_param = compute_barrett_reduction_parameter_with_bits(modulus, mod_bits);
+    split_into_exact_limbs(&redc_param, num_limbs, 120).map_err(|_| {
+        format!(
+            "the redc parameter of this modulus has {} bits and does not fit in the {num_limbs} limbs of \
+             BigNumParams<{num_limbs}, {mod_bits}>",
+            redc_param.bits()
+        )
+    })
+}
+
+/**
+ * @brief check that a `width`-bit unsigned integer type can be represented by BigNumParams<N, width>
+ */
+pub fn check_uint_width(width: usize) -> Result<(), String> {
+    if width == 0 {
+        return Err(String::from("uint width must be at least 1"));
     }
+    Ok(())
+}
+
+/**
+ * @brief the number of limbs N of a `width`-bit unsigned integer type, enough for its redc parameter 2^(width + 6).
+ *        this is ceil(width / 120), plus one for widths from 6 bits below a multiple of 120 up to the multiple
+ */
+pub fn uint_num_limbs(width: usize) -> usize {
+    (width + BARRETT_REDUCTION_OVERFLOW_BITS + 1).div_ceil(120)
 }
 
+/**
+ * @brief the number of limbs N of BigNumParams<N, MOD_BITS> for `modulus`: ceil(MOD_BITS / 120), or more if the
+ *        redc parameter needs them. the parameter has MOD_BITS + 7 bits for a field as for a uint, so moduli
+ *        from 6 bits below a multiple of 120 up to the multiple get one extra limb, as in `uint_num_limbs`
+ */
+pub fn instance_num_limbs(modulus: &BigUint, mod_bits: usize) -> usize {
+    let redc_param = compute_barrett_reduction_parameter_with_bits(modulus, mod_bits);
+    mod_bits.max(redc_param.bits()).div_ceil(120).max(1)
+}
+
+/**
+ * @brief the width of the unsigned integer type whose modulus is `modulus`, if the modulus is a power of two
+ */
+pub fn uint_width(modulus: &BigUint) -> Option<usize> {
+    let width = modulus.bits().checked_sub(1)?;
+    (*modulus == BigUint::one() << width).then_some(width)
+}
+
+/**
+ * @brief the width of the unsigned integer type whose modulus is `modulus`, or an error if the modulus is not
+ *        a power of two. used where a uint type is requested explicitly, which must not fall back to a field
+ */
+pub fn check_uint_modulus(modulus: &BigUint) -> Result<usize, String> {
+    uint_width(modulus).ok_or_else(|| {
+        format!(
+            "a uint modulus must be a power of two 2^N, {modulus} is not; omit --uint for a field"
+        )
+    })
+}
+
+/**
+ * @brief compute the BNInstance of a `width`-bit unsigned integer type, whose modulus is 2^width.
+ *        MOD_BITS is the integer width rather than the number of bits of the modulus,
+ *        and the modulus is never prime, so no primality test is run
+ */
+fn compute_uint_instance_parameters(width: usize) -> Result<(BigUint, BNInstance), String> {
+    check_uint_width(width)?;
+    let num_limbs = uint_num_limbs(width);
+    let modulus = BigUint::one() << width;
+    let instance = BNInstance {
+        has_multiplicative_inverse: false,
+        modulus: split_into_exact_limbs(&modulus, num_limbs, 120)?,
+        double_modulus: compute_double_modulus_limbs(&modulus, num_limbs),
+        redc_param: compute_redc_param_limbs(&modulus, width, num_limbs)
+            .expect("uint_num_limbs covers the redc parameter"),
+    };
+    Ok((modulus, instance))
+}
+
+/**
+ * @brief the imports required by the noir code produced by `compute_bn_instance_definition`
+ */
+const BN_INSTANCE_IMPORTS: &str = "
+use crate::bignum::BigNum;
+use crate::bignum::derive_bignum;
+use crate::params::BigNumParams;
+";
+
 /**
  * @brief given a BNInstance, construct a string that represents noir code that defines a BigNumInstance object
  */
-fn compute_bn_instance_string(
+fn compute_bn_instance_string(num_bits: usize, instance: &BNInstance, naming: &Naming) -> String {
+    let param_str: String = String::from(BN_INSTANCE_IMPORTS);
+
+    param_str + "\n" + &compute_bn_instance_definition(num_bits, instance, naming)
+}
+
+/**
+ * @brief given a BNInstance, construct the noir BigNumParams global and BigNum struct definitions, without imports
+ */
+fn compute_bn_instance_definition(
     num_bits: usize,
     instance: &BNInstance,
-    name: String,
-    underscore_split: bool,
+    naming: &Naming,
 ) -> String {
+    let mut r: String = String::new();
+    write_bn_instance_definition(&mut r, num_bits, instance, naming)
+        .expect("writing to a String cannot fail");
+    r
+}
+
+/**
+ * @brief stream the noir BigNumParams global and BigNum struct definitions of a BNInstance into `w`
+ */
+fn write_bn_instance_definition<W: fmt::Write>(
+    w: &mut W,
+    num_bits: usize,
+    instance: &BNInstance,
+    naming: &Naming,
+) -> fmt::Result {
+    let Naming {
+        struct_name,
+        params_name,
+        ..
+    } = naming;
     let BNInstance {
         has_multiplicative_inverse,
         modulus,
         double_modulus,
         redc_param,
     } = instance;
-    let num_limbs: usize = num_bits / 120 + (num_bits % 120 != 0) as usize;
-
-    let bits: String = String::from(itoa::Buffer::new().format(num_bits as u64));
-    let limbs: String = String::from(itoa::Buffer::new().format(num_limbs as u64));
+    let num_limbs: usize = modulus.len();
 
-    let mut params: String = String::from("Params");
-    if underscore_split {
-        params = String::from("_Params");
-    }
-
-    let tparam: String = name.clone() + &params;
-
-    let mut param_str: String = String::from("");
-    param_str += &String::from(format!(
-        "
-use crate::bignum::BigNum;
-use crate::bignum::derive_bignum;
-use crate::params::BigNumParams;
-"));
-
-    let mut r: String = String::from("");
-    r += &String::from(format!(
-        "pub global {}_PARAMS: BigNumParams<{}, {}> = BigNumParams {{
-        has_multiplicative_inverse: {},
+    write!(
+        w,
+        "pub global {params_name}: BigNumParams<{num_limbs}, {num_bits}> = BigNumParams {{
+        has_multiplicative_inverse: {has_multiplicative_inverse},
         modulus: [
-            ",
-        name.as_str(),
-        limbs,
-        bits,
-        has_multiplicative_inverse
-    ));
-    for i in 0..num_limbs - 1 {
-        let bytes = modulus[i].to_bytes_be();
-        r += &format!("0x{}, ", hex::encode(&bytes));
-    }
-    let bytes: Vec<u8> = modulus[num_limbs - 1].to_bytes_be();
-    r += &format!(
-        "0x{}
+            "
+    )?;
+    write_hex_limbs(w, modulus)?;
+    w.write_str(
+        "
         ],
         double_modulus: [
             ",
-        hex::encode(&bytes)
-    );
-    for i in 0..num_limbs - 1 {
-        let bytes = double_modulus[i].to_bytes_be();
-        r += &format!("0x{}, ", hex::encode(&bytes));
-    }
-    let bytes: Vec<u8> = double_modulus[num_limbs - 1].to_bytes_be();
-    r += &format!(
-        "0x{}
+    )?;
+    write_hex_limbs(w, double_modulus)?;
+    w.write_str(
+        "
         ],
         redc_param: [
             ",
-        hex::encode(&bytes)
-    );
-    for i in 0..num_limbs - 1 {
-        let bytes = redc_param[i].to_bytes_be();
-        r += &format!("0x{}, ", hex::encode(&bytes));
-    }
-    let bytes: Vec<u8> = redc_param[num_limbs - 1].to_bytes_be();
-    r += &format!(
-        "0x{}
+    )?;
+    write_hex_limbs(w, redc_param)?;
+    write!(
+        w,
+        "
         ]
     }};
 
-#[derive_bignum({}, {}, quote {{ {}_PARAMS }})]
-pub struct {} {{limbs: [u128; {}]}}
+#[derive_bignum({num_limbs}, {num_bits}, quote {{ {params_name} }})]
+pub struct {struct_name} {{limbs: [u128; {num_limbs}]}}
 
-    ",
-        hex::encode(&bytes), limbs, bits, name, name, limbs 
-    );
-    
-    param_str + "\n" + &r
+    "
+    )
+}
+
+/**
+ * @brief stream a limb as a hex literal, with an even number of digits (i.e. a whole number of bytes)
+ */
+fn write_hex_limb<W: fmt::Write>(w: &mut W, limb: &BigUint) -> fmt::Result {
+    w.write_str("0x")?;
+    for byte in limb.to_bytes_be() {
+        write!(w, "{byte:02x}")?;
+    }
+    Ok(())
+}
+
+/**
+ * @brief stream comma separated hex literals of a list of limbs
+ */
+fn write_hex_limbs<W: fmt::Write>(w: &mut W, limbs: &[BigUint]) -> fmt::Result {
+    for (i, limb) in limbs.iter().enumerate() {
+        if i > 0 {
+            w.write_str(", ")?;
+        }
+        write_hex_limb(w, limb)?;
+    }
+    Ok(())
+}
+
+/**
+ * @brief stream limbs as a noir array literal of hex values
+ */
+pub fn write_limbs<W: fmt::Write>(w: &mut W, limbs: &[BigUint]) -> fmt::Result {
+    w.write_char('[')?;
+    write_hex_limbs(w, limbs)?;
+    w.write_char(']')
 }
 
 /**
  * @brief given a string that represents a BigNum, convert into a BigUint
  *
- * @param bignum_str = base10 or base16 representation. base16 can be prepended with "0x"
+ * @param bignum_str = base10, or base16/base2/base8 when prepended with "0x"/"0b"/"0o", optionally with `_` separators.
+ *                     simple expressions such as `2^255 - 19` are evaluated, see `evaluate_integer_expression`
  */
-pub fn bignum_from_string(bignum_str: String) -> BigUint {
-    let mut formatted_bignum_str = bignum_str.clone();
-    let mut base16 = false;
-    if bignum_str.starts_with("0x") {
-        formatted_bignum_str = bignum_str.split_once("0x").unwrap().1.to_string();
-        base16 = true;
-    }
-    if bignum_str.contains("a")
-        | bignum_str.contains("b")
-        | bignum_str.contains("c")
-        | bignum_str.contains("d")
-        | bignum_str.contains("e")
-        | bignum_str.contains("f")
-        | bignum_str.contains("A")
-        | bignum_str.contains("B")
-        | bignum_str.contains("C")
-        | bignum_str.contains("D")
-        | bignum_str.contains("E")
-        | bignum_str.contains("F")
-    {
-        base16 = true;
+pub fn try_bignum_from_string(bignum_str: &str) -> Result<BigUint, String> {
+    let value = evaluate_integer_expression(bignum_str)?;
+    value.to_biguint().ok_or_else(|| {
+        format!(
+            "`{}` is negative ({value}), expected a non-negative integer",
+            bignum_str.trim()
+        )
+    })
+}
+
+/**
+ * @brief given a string that represents a signed BigNum, convert into a BigInt
+ *
+ * @param bignum_str = anything accepted by `try_bignum_from_string`, which may evaluate to a negative value
+ */
+pub fn try_signed_bignum_from_string(bignum_str: &str) -> Result<BigInt, String> {
+    evaluate_integer_expression(bignum_str)
+}
+
+/**
+ * @brief encode a signed value as an integer modulo `modulus`: x when x >= 0, modulus - |x| when x < 0.
+ *        for a field this is x mod p, for an N-bit uint (modulus 2^N) it is the two's complement of x.
+ *        errors if |x| >= modulus
+ */
+pub fn encode_signed(value: &BigInt, modulus: &BigUint) -> Result<BigUint, String> {
+    let magnitude = value
+        .abs()
+        .to_biguint()
+        .expect("the absolute value is not negative");
+    if &magnitude >= modulus {
+        return Err(format!(
+            "{value} is out of range: its absolute value must be less than the modulus 0x{}",
+            modulus.to_str_radix(16)
+        ));
     }
-    let mut base: u32 = 10;
-    if base16 {
-        base = 16;
+    match value.sign() {
+        Sign::Minus => Ok(modulus - magnitude),
+        _ => Ok(magnitude),
     }
-    let modulus_result = BigUint::from_str_radix(formatted_bignum_str.as_str(), base);
-    let modulus = match modulus_result {
+}
+
+/**
+ * @brief given a string that represents a BigNum, convert into a BigUint. panics if the string cannot be parsed
+ *
+ * @param bignum_str = anything accepted by `try_bignum_from_string`
+ */
+pub fn bignum_from_string(bignum_str: String) -> BigUint {
+    match try_bignum_from_string(bignum_str.as_str()) {
         Ok(val) => val,
-        Err(error) => panic!("Problem parsing input integer: {error:?}"),
-    };
-    modulus
+        Err(error) => panic!("{error}"),
+    }
 }
 
 /**
  * @brief Compute noir code for a bignum::BigNumInstance given a modulus String
  */
 pub fn bn_instance_from_string(modulus_str: String, name: String, is_uint: bool) -> String {
-    let modulus = bignum_from_string(modulus_str);
+    bn_instance(bignum_from_string(modulus_str), name, is_uint)
+}
+
+/**
+ * @brief Compute noir code for a bignum::BigNumInstance given a modulus.
+ *        with `is_uint`, a power-of-two modulus 2^N is generated as an N-bit unsigned integer (see `uint_instance`),
+ *        any other modulus panics
+ */
+pub fn bn_instance(modulus: BigUint, name: String, is_uint: bool) -> String {
+    bn_instance_with_rng(modulus, name, is_uint, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief `bn_instance` with the base of the primality test drawn from `rng`
+ */
+pub fn bn_instance_with_rng<R: RngCore + ?Sized>(
+    modulus: BigUint,
+    name: String,
+    is_uint: bool,
+    rng: &mut R,
+) -> String {
+    if is_uint {
+        return check_uint_modulus(&modulus)
+            .and_then(|width| uint_instance(width, name))
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    let num_bits = modulus.bits();
+    compute_bn_instance_string(
+        num_bits,
+        &compute_bn_instance_parameters(&modulus, num_bits, rng)
+            .unwrap_or_else(|error| panic!("{error}")),
+        &Naming::new(&name),
+    )
+}
+
+/**
+ * @brief Compute noir code for a bignum::BigNumInstance given a modulus String,
+ *        followed by a noir test module that checks the instance against known-answer vectors
+ */
+pub fn bn_instance_with_tests_from_string(
+    modulus_str: String,
+    name: String,
+    is_uint: bool,
+) -> String {
+    bn_instance_with_tests(bignum_from_string(modulus_str), name, is_uint)
+}
+
+/**
+ * @brief Compute noir code for a bignum::BigNumInstance given a modulus,
+ *        followed by a noir test module that checks the instance against known-answer vectors
+ */
+pub fn bn_instance_with_tests(modulus: BigUint, name: String, is_uint: bool) -> String {
+    bn_instance_with_tests_with_rng(modulus, name, is_uint, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief `bn_instance_with_tests` with the base of the primality test drawn from `rng`
+ */
+pub fn bn_instance_with_tests_with_rng<R: RngCore + ?Sized>(
+    modulus: BigUint,
+    name: String,
+    is_uint: bool,
+    rng: &mut R,
+) -> String {
+    if is_uint {
+        return check_uint_modulus(&modulus)
+            .and_then(|width| uint_instance_with_tests(width, name))
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    let num_bits = modulus.bits();
+    let instance = compute_bn_instance_parameters(&modulus, num_bits, rng)
+        .unwrap_or_else(|error| panic!("{error}"));
+    let tests = noir_tests::compute_bn_instance_test_string(
+        &modulus,
+        instance.modulus.len(),
+        name.as_str(),
+        instance.has_multiplicative_inverse,
+    );
+    compute_bn_instance_string(num_bits, &instance, &Naming::new(&name)) + &tests
+}
+
+/**
+ * @brief Compute noir code for a bignum::BigNumInstance of a `width`-bit unsigned integer type.
+ *        the modulus 2^width is computed directly, so any width accepted by `check_uint_width` is supported
+ */
+pub fn uint_instance(width: usize, name: String) -> Result<String, String> {
+    let (_, instance) = compute_uint_instance_parameters(width)?;
+    Ok(compute_bn_instance_string(
+        width,
+        &instance,
+        &Naming::new(&name),
+    ))
+}
+
+/**
+ * @brief compute the MOD_BITS and BNInstance of a modulus. with `is_uint`, the modulus must be a power of two 2^N
+ *        and is an N-bit unsigned integer type, as in `bn_instance`
+ */
+fn compute_instance<R: RngCore + ?Sized>(
+    modulus: &BigUint,
+    is_uint: bool,
+    rng: &mut R,
+) -> Result<(usize, BNInstance), String> {
+    if modulus.bits() < 2 {
+        return Err(String::from("modulus must be at least 2"));
+    }
+    if is_uint {
+        let width = check_uint_modulus(modulus)?;
+        return Ok((width, compute_uint_instance_parameters(width)?.1));
+    }
     let num_bits = modulus.bits();
-    let result = compute_bn_instance_string(
+    Ok((
         num_bits,
-        &compute_bn_instance_parameters(&modulus, num_bits),
-        name.clone(),
-        !is_uint,
+        compute_bn_instance_parameters(modulus, num_bits, rng)?,
+    ))
+}
+
+/**
+ * @brief render the parameters of a modulus in the given language. noir output is the same as `bn_instance`,
+ *        other languages get constants with the same limbs
+ */
+pub fn instance_in_lang(
+    modulus: &BigUint,
+    name: String,
+    is_uint: bool,
+    lang: Lang,
+) -> Result<String, String> {
+    instance_in_lang_with_rng(modulus, name, is_uint, lang, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief `instance_in_lang` with the base of the primality test drawn from `rng`
+ */
+pub fn instance_in_lang_with_rng<R: RngCore + ?Sized>(
+    modulus: &BigUint,
+    name: String,
+    is_uint: bool,
+    lang: Lang,
+    rng: &mut R,
+) -> Result<String, String> {
+    instance_with_naming_with_rng(modulus, &Naming::new(&name), is_uint, lang, false, rng)
+}
+
+/**
+ * @brief render the parameters of a modulus in the given language with the names of `naming`, which are validated
+ *        first. `with_tests` appends the noir test module of `bn_instance_with_tests` and requires `Lang::Noir`
+ */
+pub fn instance_with_naming(
+    modulus: &BigUint,
+    naming: &Naming,
+    is_uint: bool,
+    lang: Lang,
+    with_tests: bool,
+) -> Result<String, String> {
+    instance_with_naming_with_rng(
+        modulus,
+        naming,
+        is_uint,
+        lang,
+        with_tests,
+        &mut seeded_rng(DEFAULT_SEED),
+    )
+}
+
+/**
+ * @brief `instance_with_naming` with the base of the primality test drawn from `rng`
+ */
+pub fn instance_with_naming_with_rng<R: RngCore + ?Sized>(
+    modulus: &BigUint,
+    naming: &Naming,
+    is_uint: bool,
+    lang: Lang,
+    with_tests: bool,
+    rng: &mut R,
+) -> Result<String, String> {
+    naming.validate_for(lang)?;
+    if with_tests && lang != Lang::Noir {
+        return Err(String::from("tests are only generated for noir output"));
+    }
+    let (num_bits, instance) = compute_instance(modulus, is_uint, rng)?;
+    let mut r = String::new();
+    match lang {
+        Lang::Noir => {
+            r.push_str(BN_INSTANCE_IMPORTS);
+            r.push('\n');
+            write_bn_instance_definition(&mut r, num_bits, &instance, naming)
+        }
+        Lang::Rust => render::write_rust_instance(&mut r, num_bits, &instance, modulus, naming),
+        Lang::Ts => render::write_ts_instance(&mut r, num_bits, &instance, modulus, naming),
+        Lang::Solidity => {
+            render::write_solidity_instance(&mut r, num_bits, &instance, modulus, naming)
+        }
+    }
+    .expect("writing to a String cannot fail");
+    if with_tests {
+        r += &noir_tests::compute_bn_instance_test_string(
+            modulus,
+            instance.modulus.len(),
+            &naming.struct_name,
+            instance.has_multiplicative_inverse,
+        );
+    }
+    Ok(r)
+}
+
+/**
+ * @brief Compute noir code for a bignum::BigNumInstance of a `width`-bit unsigned integer type,
+ *        followed by a noir test module that checks the instance against known-answer vectors
+ */
+pub fn uint_instance_with_tests(width: usize, name: String) -> Result<String, String> {
+    let (modulus, instance) = compute_uint_instance_parameters(width)?;
+    let tests = noir_tests::compute_bn_instance_test_string(
+        &modulus,
+        instance.modulus.len(),
+        name.as_str(),
+        false,
     );
-    result
+    Ok(compute_bn_instance_string(width, &instance, &Naming::new(&name)) + &tests)
 }
 
 /**
@@ -242,21 +710,61 @@ pub fn bn_limbs_from_string(bn_str: String) -> String {
 }
 
 /**
- * @brief Compute noir code for an array of 120-bit limbs that represents a BigNum object
+ * @brief format limbs as a noir array literal of hex values
  */
-pub fn bn_limbs(bn: BigUint, num_bits: usize) -> String {
-    let limbs = split_into_120_bit_limbs(&bn, num_bits);
+pub fn format_limbs(limbs: &[BigUint]) -> String {
+    let mut r = String::new();
+    write_limbs(&mut r, limbs).expect("writing to a String cannot fail");
+    r
+}
 
+/**
+ * @brief format limbs as a JSON array of hex strings
+ */
+pub fn format_limbs_json(limbs: &[BigUint]) -> String {
     let mut r = String::from("[");
-    for i in 0..limbs.len() - 1 {
-        let bytes = limbs[i].to_bytes_be();
-        r += &String::from(format!("0x{}, ", hex::encode(&bytes)));
+    for (i, limb) in limbs.iter().enumerate() {
+        if i > 0 {
+            r += ", ";
+        }
+        r.push('"');
+        write_hex_limb(&mut r, limb).expect("writing to a String cannot fail");
+        r.push('"');
     }
-    let bytes: Vec<u8> = limbs[limbs.len() - 1].to_bytes_be();
-    r += &String::from(format!("0x{}]", hex::encode(&bytes)));
+    r.push(']');
     r
 }
 
+/**
+ * @brief Compute noir code for an array of 120-bit limbs that represents a BigNum object
+ */
+pub fn bn_limbs(bn: BigUint, num_bits: usize) -> String {
+    let limbs = split_into_120_bit_limbs(&bn, num_bits);
+    format_limbs(&limbs)
+}
+
+/**
+ * @brief reduce a signed value modulo `modulus` into the range [0, modulus)
+ */
+pub fn reduce_signed(value: &BigInt, modulus: &BigUint) -> BigUint {
+    let modulus = BigInt::from_biguint(Sign::Plus, modulus.clone());
+    let reduced = ((value % &modulus) + &modulus) % &modulus;
+    reduced
+        .to_biguint()
+        .expect("the reduced value is not negative")
+}
+
+/**
+ * @brief Compute noir code for an array of 120-bit limbs that represents a signed value encoded by `encode_signed`
+ */
+pub fn signed_bn_limbs(
+    value: &BigInt,
+    modulus: &BigUint,
+    num_bits: usize,
+) -> Result<String, String> {
+    Ok(bn_limbs(encode_signed(value, modulus)?, num_bits))
+}
+
 /**
  * @brief Compute noir code for an array of 120-bit limbs that represents a Barrett reduction parameter
  */
@@ -266,45 +774,112 @@ pub fn redc_limbs_from_string(bn_str: String) -> String {
 }
 
 /**
- * @brief Compute noir code for an array of 120-bit limbs that represents a Barrett reduction parameter
+ * @brief Compute noir code for the redc_param array of BigNumParams<N, num_bits> for a modulus, with the
+ *        `instance_num_limbs` limbs of the instance. `num_bits` is the MOD_BITS of the instance
  */
 pub fn redc_limbs(bn: BigUint, num_bits: usize) -> String {
-    let redc_param = compute_barrett_reduction_parameter(&bn);
-    let limbs = split_into_120_bit_limbs(&redc_param, num_bits);
+    let limbs = compute_redc_param_limbs(&bn, num_bits, instance_num_limbs(&bn, num_bits))
+        .expect("instance_num_limbs covers the redc parameter");
+    format_limbs(&limbs)
+}
 
-    let mut r = String::from("[");
-    for i in 0..limbs.len() - 1 {
-        let bytes = limbs[i].to_bytes_be();
-        r += &String::from(format!("0x{}, ", hex::encode(&bytes)));
-    }
-    let bytes: Vec<u8> = limbs[limbs.len() - 1].to_bytes_be();
-    r += &String::from(format!("0x{}]", hex::encode(&bytes)));
-    r
+/**
+ * @brief Compute noir code for the redc_param array of a `width`-bit unsigned integer type, the same as the
+ *        redc_param of `uint_instance`
+ */
+pub fn uint_redc_limbs(width: usize) -> Result<String, String> {
+    check_uint_width(width)?;
+    Ok(redc_limbs(BigUint::one() << width, width))
+}
+
+/**
+ * @brief Compute noir code for exactly `num_limbs` 120-bit limbs that represent a BigNum object.
+ *        errors if the value does not fit
+ */
+pub fn bn_limbs_padded(bn: &BigUint, num_limbs: usize) -> Result<String, String> {
+    Ok(format_limbs(&split_into_exact_limbs(bn, num_limbs, 120)?))
+}
+
+/**
+ * @brief Compute noir code for exactly `num_limbs` 120-bit limbs that represent the Barrett reduction parameter
+ *        of a modulus with MOD_BITS `num_bits`. errors if the parameter does not fit
+ */
+pub fn redc_limbs_padded(
+    modulus: &BigUint,
+    num_bits: usize,
+    num_limbs: usize,
+) -> Result<String, String> {
+    Ok(format_limbs(&compute_redc_param_limbs(
+        modulus, num_bits, num_limbs,
+    )?))
 }
 
 pub fn compute_double_modulus(modulus: &BigUint, num_bits: usize) -> Vec<BigUint> {
+    compute_double_modulus_limbs(modulus, num_bits.div_ceil(120).max(1))
+}
+
+/**
+ * @brief the double_modulus limbs of a BigNumParams with `num_limbs` limbs. every limb up to the most significant
+ *        nonzero one lends 2^120 to the limb below it, so the limbs hold 2 * modulus
+ */
+pub(crate) fn compute_double_modulus_limbs(modulus: &BigUint, num_limbs: usize) -> Vec<BigUint> {
     let double_modulus = modulus.clone() + modulus.clone();
 
-    let shift = BigUint::from(1 as u64) << 120;
-    let mut limbs = split_into_120_bit_limbs(&double_modulus, num_bits);
-    let num_limbs = limbs.len();
+    let shift = BigUint::from(1u64) << 120;
+    let mut limbs = split_into_limbs(&double_modulus, num_limbs * 120, 120);
+    // the doubled modulus may need one more bit than the limbs cover, it is kept in the most significant limb
+    if limbs.len() > num_limbs {
+        let overflow = limbs.pop().expect("limbs is not empty") << 120;
+        limbs[num_limbs - 1] += overflow;
+    }
+    // a uint with an extra limb for its redc parameter has zero limbs above the doubled modulus. if the doubled
+    // modulus fits in the first of several limbs there is no limb to lend from, so it is kept as is
+    let top = limbs.iter().rposition(|limb| !limb.is_zero()).unwrap_or(0);
+    if top == 0 && num_limbs > 1 {
+        return limbs;
+    }
     limbs[0] += shift.clone();
-    for i in 1..num_limbs - 1 {
-        limbs[i] = limbs[i].clone() + shift.clone() - BigUint::from(1 as u64);
+    for limb in limbs.iter_mut().take(top).skip(1) {
+        *limb = limb.clone() + shift.clone() - BigUint::from(1u64);
     }
-    limbs[num_limbs - 1] = limbs[num_limbs - 1].clone() - BigUint::from(1 as u64);
+    limbs[top] = limbs[top].clone() - BigUint::from(1u64);
     limbs
 }
 
+/**
+ * @brief seed of the RNG used by the functions that do not take an RNG, so that their output is reproducible
+ */
+pub const DEFAULT_SEED: u64 = 0;
+
+/**
+ * @brief the RNG of a seed. ChaCha8 is used rather than rand's StdRng, whose algorithm may change between
+ *        rand versions, so that a seed draws the same values on every platform and build
+ */
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/**
+ * @brief fermat primality test of a modulus with a base drawn from `seeded_rng(DEFAULT_SEED)`
+ */
 pub fn is_prime(modulus: &BigUint) -> bool {
+    is_prime_with_rng(modulus, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief fermat primality test of a modulus with a base drawn from `rng`
+ */
+pub fn is_prime_with_rng<R: RngCore + ?Sized>(modulus: &BigUint, rng: &mut R) -> bool {
     // we implement a fermat primality test
-    //pick a random number less than the modulus
-    let mut rng = rand::thread_rng();
-    let num_bits = modulus.bits();
-    let a: BigUint = rng.sample(RandomBits::new(num_bits));
-    let modulus_minus_1 = modulus.clone() - BigUint::from(1 as u64);
+    // pick a random base in the range [1, modulus - 1]
+    let one = BigUint::from(1u64);
+    if modulus <= &one {
+        return false;
+    }
+    let modulus_minus_1 = modulus.clone() - one.clone();
+    let a: BigUint = rng.gen_biguint_range(&one, modulus);
     let a_to_the_power = a.modpow(&modulus_minus_1, modulus);
-    a_to_the_power == BigUint::from(1 as u64)
+    a_to_the_power == one
 }
 
 #[test]
@@ -312,4 +887,215 @@ fn test_is_prime() {
     let modulus = BigUint::from(3u64);
     let is_prime = is_prime(&modulus);
     assert!(is_prime);
+
+    let mut rng = seeded_rng(1);
+    let bn254 = try_bignum_from_string(
+        "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+    )
+    .unwrap();
+    assert!(is_prime_with_rng(&bn254, &mut rng));
+    assert!(!is_prime_with_rng(&(BigUint::one() << 256usize), &mut rng));
+}
+
+#[test]
+fn test_split_into_limbs() {
+    let mut rng = seeded_rng(DEFAULT_SEED);
+    for num_bits in [1, 119, 120, 121, 254, 2048, 8193] {
+        let input: BigUint = rng.gen_biguint(num_bits);
+        for limb_bits in [1, 7, 8, 64, 120, 128, 300] {
+            let limbs = split_into_limbs(&input, num_bits, limb_bits);
+            let mask = (BigUint::from(1u64) << limb_bits) - BigUint::from(1u64);
+            let expected: Vec<BigUint> = (0..num_bits.div_ceil(limb_bits))
+                .map(|i| (input.clone() >> (i * limb_bits)) & mask.clone())
+                .collect();
+            assert_eq!(limbs, expected);
+        }
+    }
+}
+
+#[test]
+fn test_uint_instance() {
+    let from_string = bn_instance_from_string(
+        String::from("0x10000000000000000000000000000000000000000000000000000000000000000"),
+        String::from("U256"),
+        true,
+    );
+    let u256 = uint_instance(256, String::from("U256")).unwrap();
+    assert_eq!(u256, from_string);
+    assert!(u256.contains("BigNumParams<3, 256>"));
+    assert!(u256.contains("modulus: [\n            0x00, 0x00, 0x010000\n"));
+    assert!(u256.contains("redc_param: [\n            0x00, 0x00, 0x400000\n"));
+
+    // 240, 1920, 3840, 7680 and 15360 need an extra limb for their redc parameter 2^(width + 6)
+    for width in [
+        113, 114, 120, 240, 256, 384, 512, 768, 1024, 1536, 1920, 2048, 3072, 3840, 4096, 7680,
+        8192, 15360, 16384, 32768,
+    ] {
+        let (modulus, instance) = compute_uint_instance_parameters(width).unwrap();
+        let num_limbs = uint_num_limbs(width);
+        assert_eq!(
+            num_limbs,
+            width.div_ceil(120) + usize::from(width % 120 == 0 || width % 120 > 113)
+        );
+        let value = |limbs: &[BigUint]| {
+            assert_eq!(limbs.len(), num_limbs);
+            limbs
+                .iter()
+                .rev()
+                .fold(BigUint::from(0u64), |acc, limb| (acc << 120) + limb)
+        };
+        assert_eq!(value(&instance.modulus), BigUint::one() << width);
+        assert_eq!(value(&instance.redc_param), BigUint::one() << (width + 6));
+        // a single limb keeps the 2^120 it lends and the 1 it borrows, as for a one-limb field
+        let lent = if num_limbs == 1 {
+            (BigUint::one() << 120usize) - 1u64
+        } else {
+            BigUint::from(0u64)
+        };
+        assert_eq!(value(&instance.double_modulus), modulus * 2u64 + lent);
+        assert!(!instance.has_multiplicative_inverse);
+        assert_eq!(
+            uint_redc_limbs(width).unwrap(),
+            format_limbs(&instance.redc_param)
+        );
+        let generated = uint_instance(width, format!("U{width}")).unwrap();
+        assert!(generated.contains(&format!("BigNumParams<{num_limbs}, {width}>")));
+        #[cfg(feature = "std")]
+        assert_eq!(lint_bignum_params(&generated), vec![]);
+    }
+    assert!(uint_instance(7680, String::from("U7680"))
+        .unwrap()
+        .contains("BigNumParams<65, 7680>"));
+    assert!(uint_instance(0, String::from("U0")).is_err());
+    assert!(uint_redc_limbs(0).is_err());
+    // the redc parameter of a uint is 2^(width + 6), not the 2^(width + 8) of the field with the same modulus
+    assert_eq!(
+        uint_redc_limbs(256).unwrap(),
+        redc_limbs_padded(&(BigUint::one() << 256usize), 256, 3).unwrap()
+    );
+    assert_ne!(
+        uint_redc_limbs(256).unwrap(),
+        redc_limbs_from_string(String::from("2^256"))
+    );
+    // an explicit uint does not fall back to a field for a modulus that is not a power of two
+    let not_uint = BigUint::from(12345u64);
+    assert!(instance_in_lang(&not_uint, String::from("U"), true, Lang::Noir).is_err());
+    assert!(instance_in_lang(&not_uint, String::from("F"), false, Lang::Noir).is_ok());
+}
+
+#[test]
+fn test_signed_limbs() {
+    let p = BigUint::from(101u64);
+    let minus_one = try_signed_bignum_from_string("-1").unwrap();
+    assert_eq!(encode_signed(&minus_one, &p), Ok(BigUint::from(100u64)));
+    let plus = try_signed_bignum_from_string("+0x10").unwrap();
+    assert_eq!(encode_signed(&plus, &p), Ok(BigUint::from(16u64)));
+    let minus_p = try_signed_bignum_from_string("-101").unwrap();
+    assert!(encode_signed(&minus_p, &p).is_err());
+    assert_eq!(reduce_signed(&minus_p, &p), BigUint::from(0u64));
+    let large = try_signed_bignum_from_string("-(2 * 101 + 5)").unwrap();
+    assert_eq!(reduce_signed(&large, &p), BigUint::from(96u64));
+
+    let u256 = BigUint::one() << 256usize;
+    assert_eq!(
+        signed_bn_limbs(&minus_one, &u256, 256).unwrap(),
+        "[0xffffffffffffffffffffffffffffff, 0xffffffffffffffffffffffffffffff, 0xffff]"
+    );
+}
+
+#[test]
+fn test_limb_padding() {
+    let zero = BigUint::from(0u64);
+    assert_eq!(bn_limbs(zero.clone(), zero.bits()), "[0x00]");
+    assert_eq!(bn_limbs_padded(&zero, 3).unwrap(), "[0x00, 0x00, 0x00]");
+
+    // a value wider than `num_bits` is extended rather than truncated
+    let wide = BigUint::one() << 130usize;
+    assert_eq!(split_into_120_bit_limbs(&wide, 64).len(), 2);
+    assert_eq!(bn_limbs_padded(&wide, 3).unwrap(), "[0x00, 0x0400, 0x00]");
+    assert!(bn_limbs_padded(&wide, 1).is_err());
+
+    let modulus = try_bignum_from_string("2^255 - 19").unwrap();
+    assert_eq!(
+        redc_limbs_padded(&modulus, 255, 3).unwrap(),
+        redc_limbs(modulus.clone(), modulus.bits())
+    );
+    assert!(redc_limbs_padded(&modulus, 255, 2).is_err());
+
+    // 2p of a 240-bit modulus has 241 bits, the excess stays in the last of the 2 limbs
+    let modulus = (BigUint::one() << 240usize) - BigUint::from(1u64);
+    let double_modulus = compute_double_modulus(&modulus, 240);
+    assert_eq!(double_modulus.len(), 2);
+    let value = double_modulus
+        .iter()
+        .rev()
+        .fold(BigUint::from(0u64), |acc, limb| (acc << 120) + limb);
+    assert_eq!(value, modulus * 2u64);
+}
+
+#[test]
+fn test_instance_in_lang() {
+    let modulus = try_bignum_from_string(
+        "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+    )
+    .unwrap();
+    let noir = instance_in_lang(&modulus, String::from("BN254_Fq"), false, Lang::Noir).unwrap();
+    assert_eq!(
+        noir,
+        bn_instance(modulus.clone(), String::from("BN254_Fq"), false)
+    );
+
+    let rust = instance_in_lang(&modulus, String::from("BN254_Fq"), false, Lang::Rust).unwrap();
+    assert!(rust.contains("pub mod bn254_fq {"));
+    assert!(rust.contains("pub const MOD_BITS: usize = 254;"));
+    let instance =
+        compute_bn_instance_parameters(&modulus, 254, &mut seeded_rng(DEFAULT_SEED)).unwrap();
+    for limbs in [
+        &instance.modulus,
+        &instance.double_modulus,
+        &instance.redc_param,
+    ] {
+        assert!(rust.contains(&format!("[u128; 3] = {};", format_limbs(limbs))));
+    }
+    let ts = instance_in_lang(&modulus, String::from("BN254_Fq"), false, Lang::Ts).unwrap();
+    assert!(ts.contains("export const NUM_LIMBS = 3;"));
+    assert!(ts.contains(
+        "export const MODULUS: readonly bigint[] = [0x816a916871ca8d3c208c16d87cfd47n, 0x4e72e131a029b85045b68181585d97n, 0x3064n];"
+    ));
+    assert!(ts.contains("export function toLimbs(value: bigint): string[]"));
+
+    let solidity =
+        instance_in_lang(&modulus, String::from("BN254_Fq"), false, Lang::Solidity).unwrap();
+    assert!(solidity.contains("library BN254_Fq {"));
+    assert!(solidity.contains("uint256 internal constant REDC_PARAM_2 = 0x15291d;"));
+    assert!(solidity.contains("returns (uint256[3] memory limbs)"));
+
+    let naming = Naming {
+        params_name: String::from("BN254_PARAMS"),
+        ..Naming::camel_case("BN254_Fq")
+    };
+    let noir = instance_with_naming(&modulus, &naming, false, Lang::Noir, true).unwrap();
+    assert!(noir.contains("pub global BN254_PARAMS: BigNumParams<3, 254>"));
+    assert!(noir.contains("quote { BN254_PARAMS }"));
+    assert!(noir.contains("pub struct BN254Fq {limbs: [u128; 3]}"));
+    assert!(noir.contains("use super::BN254Fq;"));
+    assert!(!noir.contains("BN254_Fq"));
+    assert!(instance_with_naming(&modulus, &Naming::new("mod"), false, Lang::Noir, false).is_err());
+    assert!(instance_with_naming(&modulus, &naming, false, Lang::Rust, true).is_err());
+    assert!(instance_in_lang(&BigUint::from(0u64), String::from("Z"), false, Lang::Noir).is_err());
+
+    // the redc parameter of a k-bit field modulus has k + 7 bits, so k in 234..=240 gets a third limb as for a uint
+    for bits in 233..=241 {
+        let modulus = (BigUint::one() << bits) - 3u64;
+        let num_limbs = if bits == 233 { 2 } else { 3 };
+        assert_eq!(instance_num_limbs(&modulus, bits), num_limbs, "{bits}");
+        let noir = instance_in_lang(&modulus, String::from("F"), false, Lang::Noir).unwrap();
+        assert!(noir.contains(&format!("BigNumParams<{num_limbs}, {bits}>")));
+        let redc_param = redc_limbs(modulus.clone(), bits);
+        assert!(noir.contains(&redc_param[1..redc_param.len() - 1]));
+        #[cfg(feature = "std")]
+        assert_eq!(lint_bignum_params(&noir), vec![]);
+        let rust = instance_in_lang(&modulus, String::from("F"), false, Lang::Rust).unwrap();
+        assert!(rust.contains(&format!("REDC_PARAM: [u128; {num_limbs}]")));
+    }
 }
@@ -0,0 +1,357 @@
+use crate::decode::{integer_from_limbs, limbs_from_str};
+use num_traits::Zero;
+use rand::RngCore;
+
+use crate::{
+    compute_double_modulus_limbs, compute_redc_param_limbs, instance_num_limbs, is_prime_with_rng,
+    seeded_rng, uint_width, DEFAULT_SEED,
+};
+
+/**
+ * @brief an inconsistency found in a BigNumParams global definition
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    pub line: usize,
+    pub name: String,
+    pub message: String,
+}
+
+/**
+ * @brief a field of a BigNumParams struct literal, with the byte offset of its value
+ */
+struct ParamsField<'a> {
+    name: &'a str,
+    value: &'a str,
+    offset: usize,
+}
+
+fn line_of(source: &str, offset: usize) -> usize {
+    source[..offset].matches('\n').count() + 1
+}
+
+/**
+ * @brief replace the `//` and `/* */` comments of a noir source file with spaces, keeping newlines,
+ *        so that offsets and line numbers into the result match the source. string literals are left as they are
+ */
+fn blank_comments(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut r = bytes.to_vec();
+    let blank = |r: &mut Vec<u8>, start: usize, end: usize| {
+        for byte in &mut r[start..end] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    };
+    let mut i = 0;
+    while i < bytes.len() {
+        match (bytes[i], bytes.get(i + 1)) {
+            (b'"', _) => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            (b'/', Some(b'/')) => {
+                let end = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+                blank(&mut r, i, end);
+                i = end;
+            }
+            (b'/', Some(b'*')) => {
+                let end = source[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+                blank(&mut r, i, end);
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    String::from_utf8(r).expect("only whole comments, which start and end with ASCII, are blanked")
+}
+
+/**
+ * @brief parse the `<N, MOD_BITS>` generic arguments that follow `BigNumParams`
+ */
+fn parse_generics(generics: &str) -> Option<(usize, usize)> {
+    let (num_limbs, mod_bits) = generics.split_once(',')?;
+    Some((
+        num_limbs.trim().parse().ok()?,
+        mod_bits.trim().parse().ok()?,
+    ))
+}
+
+/**
+ * @brief split the body of a BigNumParams struct literal into its fields.
+ *        `body_offset` is the offset of `body` within the source file
+ */
+fn parse_fields(body: &str, body_offset: usize) -> Vec<ParamsField<'_>> {
+    let mut fields = Vec::new();
+    let mut cursor = 0;
+    while let Some(colon) = body[cursor..].find(':') {
+        let name_start = cursor;
+        let name = body[name_start..name_start + colon]
+            .trim()
+            .trim_start_matches(',')
+            .trim();
+        let value_start = name_start + colon + 1;
+        let rest = &body[value_start..];
+        let value_len = if rest.trim_start().starts_with('[') {
+            match rest.find(']') {
+                Some(end) => end + 1,
+                None => rest.len(),
+            }
+        } else {
+            rest.find(',').unwrap_or(rest.len())
+        };
+        let value = &rest[..value_len];
+        fields.push(ParamsField {
+            name,
+            value: value.trim(),
+            offset: body_offset + value_start + (value.len() - value.trim_start().len()),
+        });
+        cursor = value_start + value_len;
+    }
+    fields
+}
+
+/**
+ * @brief check a single BigNumParams definition against parameters recomputed from its modulus limbs
+ */
+fn lint_params<R: RngCore + ?Sized>(
+    source: &str,
+    name: &str,
+    num_limbs: usize,
+    mod_bits: usize,
+    fields: &[ParamsField],
+    decl_line: usize,
+    rng: &mut R,
+) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut report = |offset: Option<usize>, message: String| {
+        issues.push(LintIssue {
+            line: offset.map_or(decl_line, |offset| line_of(source, offset)),
+            name: name.to_string(),
+            message,
+        })
+    };
+    let field = |field_name: &str| fields.iter().find(|field| field.name == field_name);
+
+    let modulus_field = match field("modulus") {
+        Some(modulus_field) => modulus_field,
+        None => {
+            report(None, String::from("missing `modulus` field"));
+            return issues;
+        }
+    };
+    let modulus = match limbs_from_str(modulus_field.value)
+        .and_then(|limbs| integer_from_limbs(&limbs).map(|modulus| (limbs.len(), modulus)))
+    {
+        Ok((modulus_limb_count, modulus)) => {
+            if modulus_limb_count != num_limbs {
+                report(
+                    Some(modulus_field.offset),
+                    format!("`modulus` has {modulus_limb_count} limbs, expected {num_limbs}"),
+                );
+            }
+            modulus
+        }
+        Err(error) => {
+            report(Some(modulus_field.offset), format!("`modulus`: {error}"));
+            return issues;
+        }
+    };
+    if modulus.is_zero() {
+        report(Some(modulus_field.offset), String::from("modulus is zero"));
+        return issues;
+    }
+    // unsigned integer types declare their width as MOD_BITS, one less than the bits of the modulus 2^width
+    let is_uint = uint_width(&modulus) == Some(mod_bits);
+    if modulus.bits() != mod_bits && !is_uint {
+        report(
+            Some(modulus_field.offset),
+            format!(
+                "MOD_BITS is {mod_bits} but the modulus has {} bits",
+                modulus.bits()
+            ),
+        );
+    }
+    // the redc parameter may need one more limb than ceil(MOD_BITS / 120)
+    let expected_num_limbs = instance_num_limbs(&modulus, mod_bits);
+    if expected_num_limbs != num_limbs {
+        report(
+            None,
+            format!(
+                "{mod_bits}-bit parameters need {expected_num_limbs} limbs, declared {num_limbs}"
+            ),
+        );
+    }
+
+    let expected_double_modulus = compute_double_modulus_limbs(&modulus, expected_num_limbs);
+    let expected_redc_param = compute_redc_param_limbs(&modulus, mod_bits, expected_num_limbs)
+        .expect("instance_num_limbs covers the redc parameter");
+    for (field_name, expected) in [
+        ("double_modulus", &expected_double_modulus),
+        ("redc_param", &expected_redc_param),
+    ] {
+        match field(field_name) {
+            Some(params_field) => match limbs_from_str(params_field.value) {
+                Ok(limbs) if &limbs != expected => report(
+                    Some(params_field.offset),
+                    format!(
+                        "`{field_name}` does not match the modulus, expected {}",
+                        crate::format_limbs(expected)
+                    ),
+                ),
+                Ok(_) => {}
+                Err(error) => report(
+                    Some(params_field.offset),
+                    format!("`{field_name}`: {error}"),
+                ),
+            },
+            None => report(None, format!("missing `{field_name}` field")),
+        }
+    }
+
+    let expected_inverse = !is_uint && is_prime_with_rng(&modulus, rng);
+    match field("has_multiplicative_inverse") {
+        Some(params_field) => {
+            if params_field.value != expected_inverse.to_string() {
+                report(
+                    Some(params_field.offset),
+                    format!(
+                        "`has_multiplicative_inverse` is {} but the modulus is {}",
+                        params_field.value,
+                        if expected_inverse {
+                            "prime"
+                        } else {
+                            "not prime"
+                        }
+                    ),
+                );
+            }
+        }
+        None => report(
+            None,
+            String::from("missing `has_multiplicative_inverse` field"),
+        ),
+    }
+    issues
+}
+
+/**
+ * @brief find every `global NAME: BigNumParams<N, MOD_BITS> = BigNumParams { ... }` definition in a noir
+ *        source file and report each value that is inconsistent with its `modulus` limbs
+ */
+pub fn lint_bignum_params(source: &str) -> Vec<LintIssue> {
+    lint_bignum_params_with_rng(source, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief `lint_bignum_params` with the bases of the primality tests drawn from `rng`
+ */
+pub fn lint_bignum_params_with_rng<R: RngCore + ?Sized>(
+    source: &str,
+    rng: &mut R,
+) -> Vec<LintIssue> {
+    let source = &blank_comments(source);
+    let mut issues = Vec::new();
+    let mut cursor = 0;
+    while let Some(found) = source[cursor..].find("global ") {
+        let decl_start = cursor + found;
+        cursor = decl_start + "global ".len();
+        let decl = &source[cursor..];
+        let Some((name, ty)) = decl.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        let Some(generics) = ty.trim_start().strip_prefix("BigNumParams<") else {
+            continue;
+        };
+        if name.contains(char::is_whitespace) {
+            continue;
+        }
+        let decl_line = line_of(source, decl_start);
+        let Some((num_limbs, mod_bits)) =
+            generics.split_once('>').and_then(|g| parse_generics(g.0))
+        else {
+            issues.push(LintIssue {
+                line: decl_line,
+                name: name.to_string(),
+                message: String::from("could not parse BigNumParams<N, MOD_BITS> arguments"),
+            });
+            continue;
+        };
+        let (Some(open), Some(close)) = (decl.find('{'), decl.find("};")) else {
+            continue;
+        };
+        let body_offset = cursor + open + 1;
+        let fields = parse_fields(&decl[open + 1..close], body_offset);
+        issues.extend(lint_params(
+            source, name, num_limbs, mod_bits, &fields, decl_line, rng,
+        ));
+        cursor += close;
+    }
+    issues
+}
+
+#[test]
+fn test_lint_bignum_params() {
+    let generated = crate::bn_instance_from_string(
+        String::from("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"),
+        String::from("BN254_Fq"),
+        false,
+    );
+    assert_eq!(lint_bignum_params(&generated), vec![]);
+
+    let drifted = generated.replace("0x15291d", "0x15291e").replace(
+        "has_multiplicative_inverse: true",
+        "has_multiplicative_inverse: false",
+    );
+    let issues = lint_bignum_params(&drifted);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].name, "BN254_Fq_PARAMS");
+    assert!(issues[0].message.starts_with("`redc_param`"));
+    assert_eq!(issues[0].line, 14);
+    assert!(issues[1]
+        .message
+        .starts_with("`has_multiplicative_inverse`"));
+    assert_eq!(issues[1].line, 7);
+
+    let commented = "// a hand-written definition
+global F101_PARAMS: BigNumParams<1, 7> = BigNumParams {
+    has_multiplicative_inverse: true, // 101 is prime
+    modulus: [
+        0x65, // 101
+    ],
+    /* 2 * 101, with the borrow of the limb representation */
+    double_modulus: [0x010000000000000000000000000000c9],
+    redc_param: [0x288d /* 2^20 / 101 */],
+};
+";
+    assert_eq!(lint_bignum_params(commented), vec![]);
+    let issues = lint_bignum_params(&commented.replace("0x288d", "0x288e"));
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].line, 9);
+
+    let zero = "global Z: BigNumParams<1, 1> = BigNumParams {
+    has_multiplicative_inverse: false, modulus: [0x00], double_modulus: [0x00], redc_param: [0x00]
+};";
+    let issues = lint_bignum_params(zero);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].message, "modulus is zero");
+
+    // the redc parameter of 2^240 - 3 has 247 bits, so its parameters need a third limb
+    let overflow = "global F: BigNumParams<2, 240> = BigNumParams {
+    has_multiplicative_inverse: false,
+    modulus: [0xfffffffffffffffffffffffffffffd, 0xffffffffffffffffffffffffffffff],
+    double_modulus: [0x01fffffffffffffffffffffffffffffa, 0x01fffffffffffffffffffffffffffffe],
+    redc_param: [0xc0, 0x00, 0x40]
+};";
+    let issues = lint_bignum_params(overflow);
+    assert!(issues[0]
+        .message
+        .contains("240-bit parameters need 3 limbs, declared 2"));
+}
@@ -1,6 +1,19 @@
-use num_bigint::BigUint;
-use std::env;
+// the field blurbs are kept exactly as they were sourced rather than rewritten for clippy
+#![allow(clippy::useless_format, clippy::useless_conversion)]
+
+use clap::{CommandFactory, Parser};
+use num_bigint::{BigUint, Sign};
+use num_traits::One;
+use rayon::prelude::*;
 use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+mod cli;
+
+use cli::{
+    BatchFormat, BatchModeArg, Cli, Command, CostFormat, CurveArg, Format, HashArg, LangArg,
+    LimbArgs, MessageArgs, NamingArgs, TargetArgs,
+};
 
 fn bls12377_fq_blurb() -> String {
     String::from(format!("//! Blurb sourced from https://github.com/arkworks-rs
@@ -252,48 +265,467 @@ fn vesta_fr_blurb() -> String {
     ))
 }
 fn empty_blurb() -> String {
-    String::from(format!(""))
+    String::from("")
 }
 
 use noir_bignum_paramgen::{
-    bignum_from_string, bn_instance_from_string, bn_limbs_from_string, redc_limbs_from_string,
+    bignum_from_string, check_uint_modulus, check_uint_width,
+    compute_barrett_reduction_parameter_with_bits, compute_cost_report, compute_redc_param_limbs,
+    detect_special_forms, ecdsa_witness, encode_signed, format_limbs, format_limbs_json,
+    instance_num_limbs, instance_with_naming, instance_with_naming_with_rng, integer_from_limbs,
+    limbs_from_prover_toml, limbs_from_str, lint_bignum_params_with_rng, parse_batch_record,
+    parse_digest, process_batch_record_with_rng, reduce_signed, rsa_witness, seeded_rng,
+    split_into_exact_limbs, split_into_limbs, test_vectors_with_rng, try_bignum_from_string,
+    try_signed_bignum_from_string, uint_num_limbs, BatchItem, BatchMode, EcdsaCurve, HashAlgorithm,
+    Lang, Naming,
 };
 
-fn bignum_from_string_slices<const K: usize>(slices: [&str; K]) -> String {
+fn bignum_from_string_slices(slices: &[&str]) -> String {
     //  let scaling_factor = BigUint::from(1 as u64) << 120;
-    let mut result: BigUint = BigUint::from(0 as u64);
-    for i in 0..K {
-        result = result << 120;
-        let slice: BigUint = bignum_from_string(slices[K - 1 - i].to_string());
-        result = result + slice;
+    let mut result: BigUint = BigUint::from(0u64);
+    for slice in slices.iter().rev() {
+        result <<= 120;
+        let slice: BigUint = bignum_from_string(slice.to_string());
+        result += slice;
     }
     let mut r = String::from("0x");
     r.push_str(result.to_str_radix(16).as_str());
     r
 }
 
-fn generate_parameter_file<const K: usize>(
-    modulus_slices: [&str; K],
-    file_name: &str,
-    field_name: &str,
+/**
+ * @brief read a command line value. `-` reads stdin and `@path` reads a file, otherwise the argument is the value
+ */
+fn read_input(arg: &str) -> Result<String, String> {
+    if arg == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|error| format!("could not read stdin: {error}"))?;
+        Ok(input.trim().to_string())
+    } else if let Some(path) = arg.strip_prefix('@') {
+        std::fs::read_to_string(path)
+            .map(|input| input.trim().to_string())
+            .map_err(|error| format!("could not read {path}: {error}"))
+    } else {
+        Ok(arg.to_string())
+    }
+}
+
+/**
+ * @brief read a modulus command line argument as `read_input` does. a modulus must be at least 2
+ */
+fn read_modulus(arg: &str) -> Result<BigUint, String> {
+    let modulus = try_bignum_from_string(&read_input(arg)?)?;
+    if modulus.bits() < 2 {
+        return Err(String::from("modulus must be at least 2"));
+    }
+    Ok(modulus)
+}
+
+/**
+ * @brief write command output to a file, or stdout if no file is given
+ */
+fn write_output(out: Option<&Path>, output: &str) -> Result<(), String> {
+    match out {
+        Some(path) => std::fs::write(path, output)
+            .map_err(|error| format!("could not write {}: {error}", path.display())),
+        None => {
+            println!("{}", output);
+            Ok(())
+        }
+    }
+}
+
+/**
+ * @brief split a value into limbs and format them as requested on the command line
+ */
+fn format_limb_args(
+    value: &BigUint,
+    num_bits: usize,
+    limb_args: &LimbArgs,
+) -> Result<String, String> {
+    let limb_bits = limb_args.limb_bits as usize;
+    let limbs = match limb_args.num_limbs {
+        Some(num_limbs) => split_into_exact_limbs(value, num_limbs, limb_bits)?,
+        None => split_into_limbs(value, num_bits, limb_bits),
+    };
+    Ok(match limb_args.format {
+        Format::Noir => format_limbs(&limbs),
+        Format::Json => format_limbs_json(&limbs),
+    })
+}
+
+/**
+ * @brief the MOD_BITS of an instance: the integer width for the modulus of a uint type, which must be a power
+ *        of two, otherwise the bit size of the modulus
+ */
+fn mod_bits_of(modulus: &BigUint, uint: bool) -> Result<usize, String> {
+    if uint {
+        check_uint_modulus(modulus)
+    } else {
+        Ok(modulus.bits())
+    }
+}
+
+fn lang_of(lang: LangArg) -> Lang {
+    match lang {
+        LangArg::Noir => Lang::Noir,
+        LangArg::Rust => Lang::Rust,
+        LangArg::Ts => Lang::Ts,
+        LangArg::Solidity => Lang::Solidity,
+    }
+}
+
+/**
+ * @brief the names of an instance: the defaults of its parameter name, overridden by the command line
+ */
+fn naming_of(name: &str, args: NamingArgs) -> Naming {
+    let naming = if args.camel_case {
+        Naming::camel_case(name)
+    } else {
+        Naming::new(name)
+    };
+    Naming {
+        struct_name: args.struct_name.unwrap_or(naming.struct_name),
+        params_name: args.params_name.unwrap_or(naming.params_name),
+        file_name: args.file_name.unwrap_or(naming.file_name),
+        module_name: args.module_name.unwrap_or(naming.module_name),
+    }
+}
+
+fn hash_algorithm_of(hash_alg: HashArg) -> HashAlgorithm {
+    match hash_alg {
+        HashArg::Sha256 => HashAlgorithm::Sha256,
+        HashArg::Sha384 => HashAlgorithm::Sha384,
+        HashArg::Sha512 => HashAlgorithm::Sha512,
+    }
+}
+
+fn curve_of(curve: CurveArg) -> EcdsaCurve {
+    match curve {
+        CurveArg::Secp256k1 => EcdsaCurve::Secp256k1,
+        CurveArg::Secp256r1 => EcdsaCurve::Secp256r1,
+    }
+}
+
+/**
+ * @brief the digest of the message given by `--hash`, `--message` or `--message-file`
+ */
+fn message_digest(message: &MessageArgs, hash_algorithm: HashAlgorithm) -> Result<Vec<u8>, String> {
+    if let Some(hash) = &message.hash {
+        parse_digest(hash, hash_algorithm)
+    } else if let Some(text) = &message.message {
+        Ok(hash_algorithm.digest(text.as_bytes()))
+    } else if let Some(path) = &message.message_file {
+        let bytes = std::fs::read(path)
+            .map_err(|error| format!("could not read {}: {error}", path.display()))?;
+        Ok(hash_algorithm.digest(&bytes))
+    } else {
+        unreachable!("clap requires one of --hash, --message or --message-file")
+    }
+}
+
+/**
+ * @brief the modulus and MOD_BITS of the type selected by `--modulus`, `--uint` or `--params`, if any.
+ *        values encoded for the type are padded to its limb count
+ */
+fn resolve_target(target: &TargetArgs) -> Result<Option<(BigUint, usize)>, String> {
+    if let Some(modulus) = &target.modulus {
+        let modulus = read_modulus(modulus)?;
+        let num_bits = modulus.bits();
+        return Ok(Some((modulus, num_bits)));
+    }
+    if let Some(width) = target.uint {
+        check_uint_width(width)?;
+        return Ok(Some((BigUint::one() << width, width)));
+    }
+    let Some(name) = &target.params else {
+        return Ok(None);
+    };
+    let (_, modulus, num_bits) = catalogue_params(name)?;
+    Ok(Some((modulus, num_bits)))
+}
+
+/**
+ * @brief the field name, modulus and MOD_BITS of a type of the `full` catalogue, looked up case-insensitively
+ *        by field or file name
+ */
+fn catalogue_params(name: &str) -> Result<(&'static str, BigUint, usize), String> {
+    let catalogue = catalogue();
+    let entry = catalogue
+        .iter()
+        .find(|entry| {
+            entry.field_name.eq_ignore_ascii_case(name)
+                || entry.file_name.eq_ignore_ascii_case(name)
+        })
+        .ok_or_else(|| {
+            let names: Vec<&str> = catalogue.iter().map(|entry| entry.field_name).collect();
+            format!(
+                "unknown params `{name}`, expected one of {}",
+                names.join(", ")
+            )
+        })?;
+    Ok(match entry.modulus {
+        CatalogueModulus::Field(slices) => {
+            let modulus = bignum_from_string(bignum_from_string_slices(slices));
+            let num_bits = modulus.bits();
+            (entry.field_name, modulus, num_bits)
+        }
+        CatalogueModulus::Uint(width) => (entry.field_name, BigUint::one() << width, width),
+    })
+}
+
+/**
+ * @brief decode limbs back into an integer, printed in base10 and base16.
+ *
+ * @param limbs_str = noir array literal, JSON array, or `path/to/Prover.toml:key`
+ * @param modulus = if given, the integer is additionally printed reduced by this modulus
+ */
+fn integer_from_limbs_string(
+    limbs_str: String,
+    modulus: Option<&BigUint>,
+) -> Result<String, String> {
+    let limbs = match limbs_str.rsplit_once(':') {
+        Some((path, key)) if !limbs_str.trim_start().starts_with('[') => {
+            let toml_str = std::fs::read_to_string(path)
+                .map_err(|error| format!("could not read {path}: {error}"))?;
+            limbs_from_prover_toml(&toml_str, key)?
+        }
+        _ => limbs_from_str(&limbs_str)?,
+    };
+    let value = integer_from_limbs(&limbs)?;
+    let mut r = format!("decimal: {}\nhex: 0x{}", value, value.to_str_radix(16));
+    if let Some(modulus) = modulus {
+        if modulus.bits() < 2 {
+            return Err(String::from("modulus must be at least 2"));
+        }
+        let reduced = value % modulus;
+        r += &format!(
+            "\nreduced decimal: {}\nreduced hex: 0x{}",
+            reduced,
+            reduced.to_str_radix(16)
+        );
+    }
+    Ok(r)
+}
+
+/**
+ * @brief lint every BigNumParams definition in a list of noir source files.
+ *        each inconsistency is printed as `path:line: NAME: message`
+ *
+ * @return the number of issues found
+ */
+fn lint_files(paths: &[PathBuf], seed: u64) -> std::io::Result<usize> {
+    let mut num_issues = 0;
+    for path in paths {
+        let source = std::fs::read_to_string(path)?;
+        for issue in lint_bignum_params_with_rng(&source, &mut seeded_rng(seed)) {
+            println!(
+                "{}:{}: {}: {}",
+                path.display(),
+                issue.line,
+                issue.name,
+                issue.message
+            );
+            num_issues += 1;
+        }
+    }
+    Ok(num_issues)
+}
+
+/**
+ * @brief process every record of a batch input. records that fail, and records named like an earlier record,
+ *        are reported on stderr and skipped. each record draws from its own `seeded_rng(seed)`, so a record
+ *        generates the same code as the single-value command with the same seed, whatever precedes it
+ *
+ * @return the processed records, and the number of records that failed
+ */
+fn process_batch(
+    input: &str,
+    default_mode: BatchMode,
+    default_uint: bool,
+    seed: u64,
+) -> (Vec<BatchItem>, usize) {
+    let mut items = Vec::new();
+    let mut lines_by_name = std::collections::HashMap::new();
+    let mut num_errors = 0;
+    for (i, text) in input.lines().enumerate() {
+        let line = i + 1;
+        let result =
+            parse_batch_record(line, text, default_mode, default_uint).and_then(|record| {
+                match record {
+                    Some(record) => match lines_by_name.get(&record.name) {
+                        Some(first_line) => Err(format!(
+                            "duplicate name `{}`, already used on line {first_line}",
+                            record.name
+                        )),
+                        None => {
+                            process_batch_record_with_rng(&record, &mut seeded_rng(seed)).map(Some)
+                        }
+                    },
+                    None => Ok(None),
+                }
+            });
+        match result {
+            Ok(Some(item)) => {
+                lines_by_name.insert(item.name.clone(), line);
+                items.push(item)
+            }
+            Ok(None) => {}
+            Err(error) => {
+                eprintln!("line {}: {}", line, error);
+                num_errors += 1;
+            }
+        }
+    }
+    (items, num_errors)
+}
+
+/**
+ * @brief combine processed batch records into a single noir module, with each import emitted once
+ */
+fn batch_noir_module(items: &[BatchItem]) -> String {
+    let mut r = String::new();
+    for item in items {
+        if !r.contains(item.imports()) {
+            r += item.imports();
+        }
+    }
+    for item in items {
+        r += "\n";
+        r += &item.definition;
+    }
+    r
+}
+
+/**
+ * @brief format processed batch records as one JSON object per line
+ */
+fn batch_jsonl(items: &[BatchItem]) -> String {
+    let lines: Vec<String> = items
+        .iter()
+        .map(|item| {
+            let mut record = serde_json::json!({
+                "name": item.name,
+                "mode": item.mode.name(),
+                "noir": item.definition,
+            });
+            if let Some(limbs) = &item.limbs {
+                record["limbs"] = serde_json::from_str(&format_limbs_json(limbs)).unwrap();
+            }
+            record.to_string()
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/**
+ * @brief options shared by every parameter file written by `generate_parameters_full`
+ */
+struct GenerateOptions {
+    out_dir: PathBuf,
+    with_tests: bool,
+    lang: Lang,
+    camel_case: bool,
+    seed: u64,
+}
+
+/**
+ * @brief a parameter file of the field catalogue
+ */
+struct CatalogueEntry<'a> {
+    modulus: CatalogueModulus<'a>,
+    file_name: &'a str,
+    field_name: &'a str,
     blurb: String,
-    is_uint: bool,
-) -> std::io::Result<()> {
-    let mut str: String = String::from("./fields/");
-    str.push_str(file_name);
-    str.push_str(".nr");
-    let mut file = std::fs::File::create(str)?;
-    let mut file_text: String = String::from(blurb);
-    let field_inst = bn_instance_from_string(
-        bignum_from_string_slices(modulus_slices),
-        field_name.to_string(),
+}
+
+/**
+ * @brief the modulus of a catalogue entry: a prime field given as 120-bit limb strings,
+ *        or an unsigned integer type of the given width
+ */
+enum CatalogueModulus<'a> {
+    Field(&'a [&'a str]),
+    Uint(usize),
+}
+
+/**
+ * @brief summary of a parameter file written by `generate_parameter_file`
+ */
+struct WrittenFile {
+    path: PathBuf,
+    field_name: String,
+    num_bytes: usize,
+}
+
+/**
+ * @brief write a file by writing a temporary file in the same directory and renaming it over `path`,
+ *        so that an interrupted write never leaves a partially written file behind
+ */
+fn write_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(path.file_name().unwrap_or_default());
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+    let result = std::fs::File::create(&tmp_path)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|_| std::fs::rename(&tmp_path, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+fn generate_parameter_file(
+    entry: &CatalogueEntry,
+    options: &GenerateOptions,
+) -> std::io::Result<WrittenFile> {
+    let naming = Naming {
+        file_name: entry.file_name.to_string(),
+        ..if options.camel_case {
+            Naming::camel_case(entry.field_name)
+        } else {
+            Naming::new(entry.field_name)
+        }
+    };
+    let path = options
+        .out_dir
+        .join(format!("{}.{}", naming.file_name, options.lang.extension()));
+    // the blurbs are noir `//!` module docs, other languages start with the header of their renderer
+    let mut file_text: String = if options.lang == Lang::Noir {
+        entry.blurb.clone()
+    } else {
+        String::new()
+    };
+    let (modulus, is_uint) = match &entry.modulus {
+        CatalogueModulus::Field(slices) => {
+            (bignum_from_string(bignum_from_string_slices(slices)), false)
+        }
+        CatalogueModulus::Uint(width) => (BigUint::one() << *width, true),
+    };
+    // every file gets its own RNG, so that its contents do not depend on the order files are generated in
+    let field_inst = instance_with_naming_with_rng(
+        &modulus,
+        &naming,
         is_uint,
-    );
+        options.lang,
+        options.with_tests,
+        &mut seeded_rng(options.seed),
+    )
+    .map_err(std::io::Error::other)?;
     file_text.push_str(field_inst.as_str());
 
-    file.write_all(file_text.as_bytes())?;
+    write_atomically(&path, file_text.as_bytes())?;
 
-    Ok(())
+    Ok(WrittenFile {
+        path,
+        field_name: entry.field_name.to_string(),
+        num_bytes: file_text.len(),
+    })
 }
 
 // fn generate_testparams() {
@@ -320,41 +752,44 @@ fn generate_parameter_file<const K: usize>(
 //     generate_parameter_file(modulus, "test2048", "test2048", empty_blurb(), false);
 // }
 
-fn generate_parameters_full() -> std::io::Result<()> {
-    let bls381_fq: [&str; 4] = [
+/**
+ * @brief the fields and unsigned integer types written by `full`
+ */
+fn catalogue() -> Vec<CatalogueEntry<'static>> {
+    let bls381_fq: &[&str] = &[
         "0xabfffeb153ffffb9feffffffffaaab",
         "0x4b84f38512bf6730d2a0f6b0f6241e",
         "0xea397fe69a4b1ba7b6434bacd76477",
         "0x1a0111",
     ];
-    let bls381_fr: [&str; 3] = [
+    let bls381_fr: &[&str] = &[
         "0xbda402fffe5bfeffffffff00000001",
         "0xa753299d7d483339d80809a1d80553",
         "0x73ed",
     ];
-    let bls377_fq: [&str; 4] = [
+    let bls377_fq: &[&str] = &[
         "0x0b5d44300000008508c00000000001",
         "0xd9f300f5138f1ef3622fba09480017",
         "0x4617c510eac63b05c06ca1493b1a22",
         "0x01ae3a",
     ];
-    let bls377_fr: [&str; 3] = [
+    let bls377_fr: &[&str] = &[
         "0xaa76fed00000010a11800000000001",
         "0x655e9a2ca55660b44d1e5c37b00159",
         "0x12ab",
     ];
-    let bn254_fq: [&str; 3] = [
+    let bn254_fq: &[&str] = &[
         "0x816a916871ca8d3c208c16d87cfd47",
         "0x4e72e131a029b85045b68181585d97",
         "0x3064",
     ];
-    let ed25519_fq: [&str; 3] = [
+    let ed25519_fq: &[&str] = &[
         "0xffffffffffffffffffffffffffffed",
         "0xffffffffffffffffffffffffffffff",
         "0x7fff",
     ];
-    let ed25519_fr: [&str; 3] = ["0xdef9dea2f79cd65812631a5cf5d3ed", "0x14", "0x1000"];
-    let mnt4753_fq: [&str; 7] = [
+    let ed25519_fr: &[&str] = &["0xdef9dea2f79cd65812631a5cf5d3ed", "0x14", "0x1000"];
+    let mnt4753_fq: &[&str] = &[
         "0x9d54522cdd119f5e9063de245e8001",
         "0xcce9767254a4638810719ac425f0e3",
         "0x76f218059db80f0da5cb537e38685a",
@@ -363,7 +798,7 @@ fn generate_parameters_full() -> std::io::Result<()> {
         "0xc41110229022eee2cdadb7f997505b",
         "0x01c4c62d92",
     ];
-    let mnt4753_fr: [&str; 7] = [
+    let mnt4753_fr: &[&str] = &[
         "0xa099170fa13a4fd90776e240000001",
         "0xf97634993aa4d6c381bc3f0057974e",
         "0x28c859a99b3eebca9429212636b9df",
@@ -372,7 +807,7 @@ fn generate_parameters_full() -> std::io::Result<()> {
         "0xc41110229022eee2cdadb7f997505b",
         "0x01c4c62d92",
     ];
-    let mnt6753_fq: [&str; 7] = [
+    let mnt6753_fq: &[&str] = &[
         "0xa099170fa13a4fd90776e240000001",
         "0xf97634993aa4d6c381bc3f0057974e",
         "0x28c859a99b3eebca9429212636b9df",
@@ -381,7 +816,7 @@ fn generate_parameters_full() -> std::io::Result<()> {
         "0xc41110229022eee2cdadb7f997505b",
         "0x01c4c62d92",
     ];
-    let mnt6753_fr: [&str; 7] = [
+    let mnt6753_fr: &[&str] = &[
         "0x9d54522cdd119f5e9063de245e8001",
         "0xcce9767254a4638810719ac425f0e3",
         "0x76f218059db80f0da5cb537e38685a",
@@ -390,310 +825,739 @@ fn generate_parameters_full() -> std::io::Result<()> {
         "0xc41110229022eee2cdadb7f997505b",
         "0x01c4c62d92",
     ];
-    let pallas_fq: [&str; 3] = ["0x4698fc094cf91b992d30ed00000001", "0x22", "0x4000"];
-    let pallas_fr: [&str; 3] = ["0x4698fc0994a8dd8c46eb2100000001", "0x22", "0x4000"];
-    let secp256k1_fq: [&str; 3] = [
+    let pallas_fq: &[&str] = &["0x4698fc094cf91b992d30ed00000001", "0x22", "0x4000"];
+    let pallas_fr: &[&str] = &["0x4698fc0994a8dd8c46eb2100000001", "0x22", "0x4000"];
+    let secp256k1_fq: &[&str] = &[
         "0xfffffffffffffffffffffefffffc2f",
         "0xffffffffffffffffffffffffffffff",
         "0xffff",
     ];
-    let secp256k1_fr: [&str; 3] = [
+    let secp256k1_fr: &[&str] = &[
         "0xaedce6af48a03bbfd25e8cd0364141",
         "0xfffffffffffffffffffffffffffeba",
         "0xffff",
     ];
-    let secp256r1_fq: [&str; 3] = [
+    let secp256r1_fq: &[&str] = &[
         "0xffffffffffffffffffffffff",
         "0xffff00000001000000000000000000",
         "0xffff",
     ];
-    let secp256r1_fr: [&str; 3] = [
+    let secp256r1_fr: &[&str] = &[
         "0xe6faada7179e84f3b9cac2fc632551",
         "0xffff00000000ffffffffffffffffbc",
         "0xffff",
     ];
-    let secp384r1_fq: [&str; 4] = [
+    let secp384r1_fq: &[&str] = &[
         "0xffffff0000000000000000ffffffff",
         "0xfffffffffffffffffffffffffffeff",
         "0xffffffffffffffffffffffffffffff",
         "0xffffff",
     ];
-    let secp384r1_fr: [&str; 4] = [
+    let secp384r1_fr: &[&str] = &[
         "0x1a0db248b0a77aecec196accc52973",
         "0xffffffffffffc7634d81f4372ddf58",
         "0xffffffffffffffffffffffffffffff",
         "0xffffff",
     ];
-    let vesta_fq: [&str; 3] = ["0x4698fc0994a8dd8c46eb2100000001", "0x22", "0x4000"];
-    let vesta_fr: [&str; 3] = ["0x4698fc094cf91b992d30ed00000001", "0x22", "0x4000"];
-    let u256: [&str; 3] = ["0x00", "0x00", "0x010000"];
-    let u384: [&str; 4] = ["0x00", "0x00", "0x00", "0x01000000"];
-    let u512: [&str; 5] = ["0x00", "0x00", "0x00", "0x00", "0x0100000000"];
-    let u768: [&str; 7] = [
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x01000000000000",
-    ];
-    let u1024: [&str; 9] = [
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x010000000000000000",
-    ];
-    let u2048: [&str; 18] = [
-        "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00",
-        "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x0100",
-    ];
-    let u4096: [&str; 35] = [
-        "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00",
-        "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00",
-        "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00", "0x00",
-        "0x00", "0x010000",
-    ];
-    let u8192: [&str; 69] = [
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x00",
-        "0x0100000000",
-    ];
+    let vesta_fq: &[&str] = &["0x4698fc0994a8dd8c46eb2100000001", "0x22", "0x4000"];
+    let vesta_fr: &[&str] = &["0x4698fc094cf91b992d30ed00000001", "0x22", "0x4000"];
 
-    generate_parameter_file(
-        bls377_fq,
-        "bls12_377Fq",
-        "BLS12_377_Fq",
-        bls12377_fq_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        bls377_fr,
-        "bls12_377Fr",
-        "BLS12_377_Fr",
-        bls12377_fr_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        bls381_fq,
-        "bls12_381Fq",
-        "BLS12_381_Fq",
-        bls12381_fq_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        bls381_fr,
-        "bls12_381Fr",
-        "BLS12_381_Fr",
-        bls12381_fr_blurb(),
-        false,
-    )?;
-    generate_parameter_file(bn254_fq, "bn254Fq", "BN254_Fq", empty_blurb(), false)?;
-    generate_parameter_file(ed25519_fq, "ed25519Fq", "ED25519_Fq", empty_blurb(), false)?;
-    generate_parameter_file(ed25519_fr, "ed25519Fr", "ED25519_Fr", empty_blurb(), false)?;
-    generate_parameter_file(
-        mnt4753_fq,
-        "mnt4_753Fq",
-        "MNT4_753_Fq",
-        mnt4753_fq_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        mnt4753_fr,
-        "mnt4_753Fr",
-        "MNT4_753_Fr",
-        mnt4753_fr_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        mnt6753_fq,
-        "mnt6_753Fq",
-        "MNT6_753_Fq",
-        mnt6753_fq_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        mnt6753_fr,
-        "mnt6_753Fr",
-        "MNT6_753_Fr",
-        mnt6753_fr_blurb(),
-        false,
-    )?;
-    generate_parameter_file(pallas_fq, "pallasFq", "Pallas_Fq", pallas_fq_blurb(), false)?;
-    generate_parameter_file(pallas_fr, "pallasFr", "Pallas_Fr", pallas_fr_blurb(), false)?;
-    generate_parameter_file(vesta_fq, "vestaFq", "Vesta_Fq", vesta_fq_blurb(), false)?;
-    generate_parameter_file(vesta_fr, "vestaFr", "Vesta_Fr", vesta_fr_blurb(), false)?;
-    generate_parameter_file(
-        secp256k1_fq,
-        "secp256k1Fq",
-        "Secp256k1_Fq",
-        empty_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        secp256k1_fr,
-        "secp256k1Fr",
-        "Secp256k1_Fr",
-        empty_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        secp256r1_fq,
-        "secp256r1Fq",
-        "Secp256r1_Fq",
-        empty_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        secp256r1_fr,
-        "secp256r1Fr",
-        "Secp256r1_Fr",
-        empty_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        secp384r1_fq,
-        "secp384r1Fq",
-        "Secp384r1_Fq",
-        empty_blurb(),
-        false,
-    )?;
-    generate_parameter_file(
-        secp384r1_fr,
-        "secp384r1Fr",
-        "Secp384r1_Fr",
-        empty_blurb(),
-        false,
-    )?;
-    generate_parameter_file(u256, "U256", "U256", empty_blurb(), true)?;
-    generate_parameter_file(u384, "U384", "U384", empty_blurb(), true)?;
-    generate_parameter_file(u512, "U512", "U512", empty_blurb(), true)?;
-    generate_parameter_file(u768, "U768", "U768", empty_blurb(), true)?;
-    generate_parameter_file(u1024, "U1024", "U1024", empty_blurb(), true)?;
-    generate_parameter_file(u2048, "U2048", "U2048", empty_blurb(), true)?;
-    generate_parameter_file(u4096, "U4096", "U4096", empty_blurb(), true)?;
-    generate_parameter_file(u8192, "U8192", "U8192", empty_blurb(), true)?;
-    Ok(())
+    vec![
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(bls377_fq),
+            file_name: "bls12_377Fq",
+            field_name: "BLS12_377_Fq",
+            blurb: bls12377_fq_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(bls377_fr),
+            file_name: "bls12_377Fr",
+            field_name: "BLS12_377_Fr",
+            blurb: bls12377_fr_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(bls381_fq),
+            file_name: "bls12_381Fq",
+            field_name: "BLS12_381_Fq",
+            blurb: bls12381_fq_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(bls381_fr),
+            file_name: "bls12_381Fr",
+            field_name: "BLS12_381_Fr",
+            blurb: bls12381_fr_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(bn254_fq),
+            file_name: "bn254Fq",
+            field_name: "BN254_Fq",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(ed25519_fq),
+            file_name: "ed25519Fq",
+            field_name: "ED25519_Fq",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(ed25519_fr),
+            file_name: "ed25519Fr",
+            field_name: "ED25519_Fr",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(mnt4753_fq),
+            file_name: "mnt4_753Fq",
+            field_name: "MNT4_753_Fq",
+            blurb: mnt4753_fq_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(mnt4753_fr),
+            file_name: "mnt4_753Fr",
+            field_name: "MNT4_753_Fr",
+            blurb: mnt4753_fr_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(mnt6753_fq),
+            file_name: "mnt6_753Fq",
+            field_name: "MNT6_753_Fq",
+            blurb: mnt6753_fq_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(mnt6753_fr),
+            file_name: "mnt6_753Fr",
+            field_name: "MNT6_753_Fr",
+            blurb: mnt6753_fr_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(pallas_fq),
+            file_name: "pallasFq",
+            field_name: "Pallas_Fq",
+            blurb: pallas_fq_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(pallas_fr),
+            file_name: "pallasFr",
+            field_name: "Pallas_Fr",
+            blurb: pallas_fr_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(vesta_fq),
+            file_name: "vestaFq",
+            field_name: "Vesta_Fq",
+            blurb: vesta_fq_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(vesta_fr),
+            file_name: "vestaFr",
+            field_name: "Vesta_Fr",
+            blurb: vesta_fr_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(secp256k1_fq),
+            file_name: "secp256k1Fq",
+            field_name: "Secp256k1_Fq",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(secp256k1_fr),
+            file_name: "secp256k1Fr",
+            field_name: "Secp256k1_Fr",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(secp256r1_fq),
+            file_name: "secp256r1Fq",
+            field_name: "Secp256r1_Fq",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(secp256r1_fr),
+            file_name: "secp256r1Fr",
+            field_name: "Secp256r1_Fr",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(secp384r1_fq),
+            file_name: "secp384r1Fq",
+            field_name: "Secp384r1_Fq",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Field(secp384r1_fr),
+            file_name: "secp384r1Fr",
+            field_name: "Secp384r1_Fr",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(256),
+            file_name: "U256",
+            field_name: "U256",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(384),
+            file_name: "U384",
+            field_name: "U384",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(512),
+            file_name: "U512",
+            field_name: "U512",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(768),
+            file_name: "U768",
+            field_name: "U768",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(1024),
+            file_name: "U1024",
+            field_name: "U1024",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(1536),
+            file_name: "U1536",
+            field_name: "U1536",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(2048),
+            file_name: "U2048",
+            field_name: "U2048",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(3072),
+            file_name: "U3072",
+            field_name: "U3072",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(4096),
+            file_name: "U4096",
+            field_name: "U4096",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(8192),
+            file_name: "U8192",
+            field_name: "U8192",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(16384),
+            file_name: "U16384",
+            field_name: "U16384",
+            blurb: empty_blurb(),
+        },
+        CatalogueEntry {
+            modulus: CatalogueModulus::Uint(32768),
+            file_name: "U32768",
+            field_name: "U32768",
+            blurb: empty_blurb(),
+        },
+    ]
 }
 
 /**
- * @brief given a big number command line argument (either base10 or base 16 e.g. "1234", "0xabcd", "abcd" are all valid), spit out BigNum parameters formatted for noir.  
+ * @brief write every entry of the field catalogue into `options.out_dir`.
+ *        instances are generated in parallel, the returned summary is in catalogue order
  */
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let valid_args = args.len() == 3 || args.len() == 4 || (args.len() == 2 && &args[1] == "full");
-
-    let help_msg = "usage: noir-bignum-paramgen [instance, runtime_instance, limbs, redc_param, full] ?[bignum modulus in hex or decimal] ?[parameter name]
-        instance: outputs a string that represents a bignum::BigNumInstance object
-        runtime_instance: outputs a string that represents a bignum::runtime_bignum::BigNumInstance object
-        limbs: outputs an array of Field elements that represents a BigNum object's 120-bit limbs
-        redc_param: outputs an array of Field elements that represents a BigNumInstance Barret reduction parameter
-        full: writes a list of .nr parameter files for all currently supported BigNum fields, into the `fields` directory
-
-EXAMPLE:
-    noir-bignum-paramgen instance 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab BLS12_381_Fq";
-    if !valid_args {
-        println!("{}", help_msg);
-    } else {
-        let instruction_type = &args[1];
+fn generate_parameters_full(options: &GenerateOptions) -> std::io::Result<Vec<WrittenFile>> {
+    std::fs::create_dir_all(&options.out_dir)?;
+    catalogue()
+        .par_iter()
+        .map(|entry| generate_parameter_file(entry, options))
+        .collect()
+}
 
-        let mut input_number: String = String::from("");
-        if args.len() > 2 {
-            input_number = args[2].clone();
+/**
+ * @brief given a big number command line argument (base10, or base16/base2/base8 with a 0x/0b/0o prefix, e.g. "1234", "0xabcd", "2^255 - 19"), spit out BigNum parameters formatted for noir.
+ */
+fn run(cli: Cli) -> Result<(), String> {
+    let seed = cli.seed;
+    match cli.command {
+        Command::Instance {
+            modulus,
+            name,
+            positional_name,
+            uint,
+            tests,
+            cost,
+            special_form,
+            lang,
+            naming,
+            output,
+            out_dir,
+        } => {
+            let lang = lang_of(lang);
+            if lang != Lang::Noir && (tests || special_form) {
+                return Err(String::from(
+                    "--tests and --special-form are only supported with --lang noir",
+                ));
+            }
+            let modulus = read_modulus(&modulus)?;
+            let name = positional_name.unwrap_or(name);
+            let naming = naming_of(&name, naming);
+            let special_form_constants = if special_form {
+                detect_special_forms(&modulus).noir_constants(&name)
+            } else {
+                String::new()
+            };
+            let out = out_dir
+                .map(|out_dir| out_dir.join(format!("{}.{}", naming.file_name, lang.extension())));
+            let mut result = String::new();
+            if cost {
+                let mod_bits = mod_bits_of(&modulus, uint)?;
+                result += &compute_cost_report(mod_bits, instance_num_limbs(&modulus, mod_bits))
+                    .to_comment();
+            }
+            result += &instance_with_naming_with_rng(
+                &modulus,
+                &naming,
+                uint,
+                lang,
+                tests,
+                &mut seeded_rng(seed),
+            )?;
+            if !special_form_constants.is_empty() {
+                result += "\n";
+                result += &special_form_constants;
+            }
+            write_output(out.or(output.out).as_deref(), &result)
         }
-        let mut name: String = String::from("BigNumInstance");
-        if args.len() == 4 {
-            name = args[3].clone();
+        Command::Uint {
+            bits,
+            name,
+            tests,
+            cost,
+            lang,
+            naming,
+            output,
+            out_dir,
+        } => {
+            let lang = lang_of(lang);
+            if lang != Lang::Noir && tests {
+                return Err(String::from("--tests is only supported with --lang noir"));
+            }
+            let name = name.unwrap_or_else(|| format!("U{bits}"));
+            let naming = naming_of(&name, naming);
+            let out = out_dir
+                .map(|out_dir| out_dir.join(format!("{}.{}", naming.file_name, lang.extension())));
+            let mut result = String::new();
+            if cost {
+                result += &compute_cost_report(bits, uint_num_limbs(bits)).to_comment();
+            }
+            result += &instance_with_naming(&(BigUint::one() << bits), &naming, true, lang, tests)?;
+            write_output(out.or(output.out).as_deref(), &result)
         }
-        let mut result = String::from(help_msg);
-        match instruction_type.as_str() {
-            "instance" => {
-                result = bn_instance_from_string(input_number, name, false);
+        Command::Limbs {
+            value,
+            signed,
+            reduce,
+            target,
+            limbs,
+            output,
+        } => {
+            let value_str = read_input(&value)?;
+            let value = try_signed_bignum_from_string(&value_str)?;
+            if !signed && value.sign() == Sign::Minus {
+                return Err(format!(
+                    "`{}` is negative ({value}); pass --signed with --modulus, --uint or --params to encode negative values",
+                    value_str.trim()
+                ));
             }
-            "limbs" => {
-                result = bn_limbs_from_string(input_number);
+            let (value, num_bits, num_limbs) = match resolve_target(&target)? {
+                Some((modulus, num_bits)) => {
+                    let encoded = if reduce {
+                        reduce_signed(&value, &modulus)
+                    } else {
+                        encode_signed(&value, &modulus)
+                            .map_err(|error| format!("{error}; pass --reduce to reduce it"))?
+                    };
+                    // 120-bit limbs are padded to N of the type, which may exceed ceil(MOD_BITS / 120) for a uint
+                    let num_limbs =
+                        limbs.num_limbs.or((limbs.limb_bits == 120)
+                            .then(|| instance_num_limbs(&modulus, num_bits)));
+                    (encoded, num_bits, num_limbs)
+                }
+                None => {
+                    let value = value.to_biguint().expect("the value is not negative");
+                    let num_bits = value.bits();
+                    (value, num_bits, limbs.num_limbs)
+                }
+            };
+            let limbs = LimbArgs { num_limbs, ..limbs };
+            let result = format_limb_args(&value, num_bits, &limbs)?;
+            write_output(output.out.as_deref(), &result)
+        }
+        Command::RedcParam {
+            modulus,
+            uint,
+            limbs,
+            output,
+        } => {
+            let modulus = read_modulus(&modulus)?;
+            let mod_bits = mod_bits_of(&modulus, uint)?;
+            let result = if limbs.limb_bits == 120 {
+                // the redc_param array of the instance, padded to its limb count unless --num-limbs is given
+                let num_limbs = limbs
+                    .num_limbs
+                    .unwrap_or_else(|| instance_num_limbs(&modulus, mod_bits));
+                let redc_param = compute_redc_param_limbs(&modulus, mod_bits, num_limbs)?;
+                match limbs.format {
+                    Format::Noir => format_limbs(&redc_param),
+                    Format::Json => format_limbs_json(&redc_param),
+                }
+            } else {
+                let redc_param = compute_barrett_reduction_parameter_with_bits(&modulus, mod_bits);
+                format_limb_args(&redc_param, mod_bits, &limbs)?
+            };
+            write_output(output.out.as_deref(), &result)
+        }
+        Command::FromLimbs {
+            limbs,
+            target,
+            positional_modulus,
+            output,
+        } => {
+            let target = TargetArgs {
+                modulus: target.modulus.or(positional_modulus),
+                ..target
+            };
+            let modulus = resolve_target(&target)?.map(|(modulus, _)| modulus);
+            let result = integer_from_limbs_string(read_input(&limbs)?, modulus.as_ref())?;
+            write_output(output.out.as_deref(), &result)
+        }
+        Command::Cost {
+            modulus,
+            uint,
+            format,
+            output,
+        } => {
+            let modulus = read_modulus(&modulus)?;
+            let mod_bits = mod_bits_of(&modulus, uint)?;
+            let report = compute_cost_report(mod_bits, instance_num_limbs(&modulus, mod_bits));
+            let result = match format {
+                CostFormat::Text => report.lines().join("\n") + "\n",
+                CostFormat::Json => report.to_json().to_string() + "\n",
+            };
+            write_output(output.out.as_deref(), &result)
+        }
+        Command::SpecialForm {
+            modulus,
+            name,
+            output,
+        } => {
+            let modulus = read_modulus(&modulus)?;
+            let forms = detect_special_forms(&modulus);
+            let result = match name {
+                Some(name) => {
+                    Naming::new(&name).validate()?;
+                    forms.noir_constants(&name)
+                }
+                None => forms.lines().join("\n") + "\n",
+            };
+            write_output(output.out.as_deref(), &result)
+        }
+        Command::Lint { files } => match lint_files(&files, seed) {
+            Ok(0) => {
+                println!("no issues found");
+                Ok(())
             }
-            "redc_param" => {
-                result = redc_limbs_from_string(input_number);
+            Ok(num_issues) => Err(format!("{num_issues} issue(s) found")),
+            Err(error) => Err(error.to_string()),
+        },
+        Command::Batch {
+            input,
+            mode,
+            uint,
+            format,
+            output,
+            out_dir,
+        } => {
+            let input = match input.as_str() {
+                "-" => read_input(&input)?,
+                path => std::fs::read_to_string(path)
+                    .map_err(|error| format!("could not read {path}: {error}"))?,
+            };
+            let default_mode = match mode {
+                BatchModeArg::Instance => BatchMode::Instance,
+                BatchModeArg::Limbs => BatchMode::Limbs,
+                BatchModeArg::RedcParam => BatchMode::RedcParam,
+            };
+            let (items, num_errors) = process_batch(&input, default_mode, uint, seed);
+            match (out_dir, format) {
+                (Some(out_dir), _) => {
+                    std::fs::create_dir_all(&out_dir).map_err(|error| error.to_string())?;
+                    for item in &items {
+                        let path = out_dir.join(format!("{}.nr", item.name));
+                        write_output(
+                            Some(&path),
+                            &(item.imports().to_string() + "\n" + &item.definition),
+                        )?;
+                    }
+                }
+                (None, BatchFormat::Noir) => {
+                    write_output(output.out.as_deref(), &batch_noir_module(&items))?
+                }
+                (None, BatchFormat::Jsonl) => {
+                    write_output(output.out.as_deref(), &batch_jsonl(&items))?
+                }
             }
-            "full" => {
-                let _ = generate_parameters_full();
-                result = String::from("parameter instances written into fields directory");
+            if num_errors > 0 {
+                return Err(format!("{num_errors} record(s) failed"));
             }
-            _ => {}
+            Ok(())
+        }
+        Command::Vectors {
+            field,
+            count,
+            format,
+            output,
+        } => {
+            let (name, modulus, num_bits) = catalogue_params(&field)?;
+            let vectors = test_vectors_with_rng(&modulus, num_bits, count, &mut seeded_rng(seed));
+            let result = match format {
+                Format::Noir => vectors.to_noir(name),
+                Format::Json => vectors.to_json().to_string() + "\n",
+            };
+            write_output(output.out.as_deref(), &result)
+        }
+        Command::RsaWitness {
+            modulus,
+            exponent,
+            signature,
+            bits,
+            message,
+            hash_alg,
+            output,
+        } => {
+            let hash_algorithm = hash_algorithm_of(hash_alg);
+            let witness = rsa_witness(
+                bits,
+                &try_bignum_from_string(&read_input(&modulus)?)?,
+                &try_bignum_from_string(&read_input(&exponent)?)?,
+                &try_bignum_from_string(&read_input(&signature)?)?,
+                &message_digest(&message, hash_algorithm)?,
+                hash_algorithm,
+            )?;
+            write_output(output.out.as_deref(), &witness.to_prover_toml())
         }
-        println!("{}", result.as_str());
+        Command::EcdsaWitness {
+            curve,
+            pubkey_x,
+            pubkey_y,
+            r,
+            s,
+            message,
+            hash_alg,
+            output,
+        } => {
+            let witness = ecdsa_witness(
+                curve_of(curve),
+                &try_bignum_from_string(&read_input(&pubkey_x)?)?,
+                &try_bignum_from_string(&read_input(&pubkey_y)?)?,
+                &try_bignum_from_string(&read_input(&r)?)?,
+                &try_bignum_from_string(&read_input(&s)?)?,
+                &message_digest(&message, hash_algorithm_of(hash_alg))?,
+            )?;
+            write_output(output.out.as_deref(), &witness.to_prover_toml())
+        }
+        Command::Full {
+            out_dir,
+            tests,
+            lang,
+            camel_case,
+        } => {
+            let lang = lang_of(lang);
+            if lang != Lang::Noir && tests {
+                return Err(String::from("--tests is only supported with --lang noir"));
+            }
+            let options = GenerateOptions {
+                out_dir,
+                with_tests: tests,
+                lang,
+                camel_case,
+                seed,
+            };
+            let written = generate_parameters_full(&options).map_err(|error| error.to_string())?;
+            for file in &written {
+                println!(
+                    "wrote {} ({}, {} bytes)",
+                    file.path.display(),
+                    file.field_name,
+                    file.num_bytes
+                );
+            }
+            println!(
+                "{} parameter instances written into {}",
+                written.len(),
+                options.out_dir.display()
+            );
+            Ok(())
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "paramgen",
+                &mut std::io::stdout(),
+            );
+            Ok(())
+        }
+        Command::Man => clap_mangen::Man::new(Cli::command())
+            .render(&mut std::io::stdout())
+            .map_err(|error| error.to_string()),
+    }
+}
+
+fn main() {
+    if let Err(error) = run(Cli::parse()) {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+#[test]
+fn test_full_is_reproducible() {
+    let generate = |run: &str| {
+        let out_dir =
+            std::env::temp_dir().join(format!("paramgen-full-{}-{run}", std::process::id()));
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let options = GenerateOptions {
+            out_dir: out_dir.clone(),
+            with_tests: false,
+            lang: Lang::Noir,
+            camel_case: false,
+            seed: noir_bignum_paramgen::DEFAULT_SEED,
+        };
+        let files: Vec<(std::ffi::OsString, Vec<u8>)> = generate_parameters_full(&options)
+            .unwrap()
+            .iter()
+            .map(|file| {
+                (
+                    file.path.file_name().unwrap().to_os_string(),
+                    std::fs::read(&file.path).unwrap(),
+                )
+            })
+            .collect();
+        std::fs::remove_dir_all(&out_dir).unwrap();
+        files
+    };
+    let first = generate("first");
+    assert_eq!(first.len(), catalogue().len());
+    assert_eq!(first, generate("second"));
+
+    // the checked-in fields directory is the output of `paramgen full`
+    let fields = Path::new(env!("CARGO_MANIFEST_DIR")).join("fields");
+    for (file_name, contents) in &first {
+        assert_eq!(
+            &std::fs::read(fields.join(file_name)).unwrap(),
+            contents,
+            "{file_name:?} differs from the checked-in file"
+        );
+    }
+}
+
+#[test]
+fn test_redc_param_matches_instance() {
+    let output = |args: &[&str]| {
+        let path =
+            std::env::temp_dir().join(format!("paramgen-redc-{}-{}", std::process::id(), args[0]));
+        let out = ["--out", path.to_str().unwrap()];
+        let argv = ["paramgen"].iter().chain(args).chain(&out);
+        run(Cli::try_parse_from(argv).unwrap()).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        text
+    };
+    // `redc-param` reproduces the redc_param of the instance, including the extra limb of 2^240 and 2^240 - 3
+    for (modulus, uint) in [
+        ("2^256", true),
+        ("2^240", true),
+        ("2^240 - 3", false),
+        ("2^255 - 19", false),
+    ] {
+        let uint = if uint { "--uint" } else { "--seed=0" };
+        let redc_param = output(&["redc-param", modulus, uint]);
+        let instance = output(&["instance", modulus, uint, "--lang", "rust"]);
+        let redc_param = redc_param.trim_end();
+        let num_limbs = redc_param.matches("0x").count();
+        assert!(
+            instance.contains(&format!("REDC_PARAM: [u128; {num_limbs}] = {redc_param};")),
+            "{modulus}"
+        );
+    }
+}
+
+#[test]
+fn test_full_headers_start_their_line() {
+    for lang in [Lang::Rust, Lang::Ts, Lang::Solidity] {
+        let out_dir = std::env::temp_dir().join(format!(
+            "paramgen-full-{}-{}",
+            std::process::id(),
+            lang.extension()
+        ));
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let options = GenerateOptions {
+            out_dir: out_dir.clone(),
+            with_tests: false,
+            lang,
+            camel_case: false,
+            seed: noir_bignum_paramgen::DEFAULT_SEED,
+        };
+        for file in generate_parameters_full(&options).unwrap() {
+            let text = std::fs::read_to_string(&file.path).unwrap();
+            // the renderer header occurs once, at the beginning of a line
+            assert_eq!(text.matches("// generated by paramgen").count(), 1);
+            assert!(
+                text.lines()
+                    .any(|line| line.starts_with("// generated by paramgen")),
+                "{:?}",
+                file.path
+            );
+            if lang == Lang::Solidity {
+                assert_eq!(
+                    text.lines().next(),
+                    Some("// SPDX-License-Identifier: Apache-2.0"),
+                    "{:?}",
+                    file.path
+                );
+            }
+        }
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
+
+#[test]
+fn test_batch_matches_instance() {
+    // 561 is a carmichael number, so whether it gets a multiplicative inverse depends on the base of the fermat
+    // test. every record draws the same base as `instance` with the same seed, whatever precedes it
+    let seed = 7;
+    let input: String = (0..8).map(|i| format!("561 C{i}\n")).collect();
+    let (items, num_errors) = process_batch(&input, BatchMode::Instance, false, seed);
+    assert_eq!((items.len(), num_errors), (8, 0));
+    let instance = noir_bignum_paramgen::instance_with_naming_with_rng(
+        &BigUint::from(561u32),
+        &Naming::new("C0"),
+        false,
+        Lang::Noir,
+        false,
+        &mut seeded_rng(seed),
+    )
+    .unwrap();
+    for item in &items {
+        assert_eq!(
+            item.definition.replace(&item.name, "C0"),
+            instance.replace(item.imports(), "").trim_start(),
+            "{}",
+            item.name
+        );
     }
 }
@@ -0,0 +1,411 @@
+use crate::prelude::*;
+use crate::Lang;
+
+/**
+ * @brief words a noir identifier cannot be: keywords, primitive types and the bool literals
+ */
+const NOIR_RESERVED_WORDS: &[&str] = &[
+    "as",
+    "assert",
+    "assert_eq",
+    "bool",
+    "break",
+    "call_data",
+    "char",
+    "comptime",
+    "constrain",
+    "continue",
+    "contract",
+    "crate",
+    "dep",
+    "else",
+    "enum",
+    "false",
+    "Field",
+    "fn",
+    "for",
+    "global",
+    "i1",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "match",
+    "mod",
+    "mut",
+    "pub",
+    "quote",
+    "return",
+    "return_data",
+    "self",
+    "Self",
+    "str",
+    "struct",
+    "super",
+    "trait",
+    "true",
+    "type",
+    "u1",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "unchecked",
+    "unconstrained",
+    "unsafe",
+    "use",
+    "where",
+    "while",
+];
+
+/**
+ * @brief the items `BN_INSTANCE_IMPORTS` brings into scope, which a generated struct or global cannot shadow
+ */
+const IMPORTED_NAMES: &[&str] = &["BigNum", "BigNumParams", "derive_bignum"];
+
+/**
+ * @brief the keywords of rust, which a module of `--lang rust` cannot be named
+ */
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/**
+ * @brief the keywords, reserved words and elementary type names of solidity other than the sized types
+ *        `intN`, `uintN`, `bytesN`, `fixedMxN` and `ufixedMxN`, which a library of `--lang solidity` cannot be named
+ */
+const SOLIDITY_KEYWORDS: &[&str] = &[
+    "abstract",
+    "address",
+    "after",
+    "alias",
+    "anonymous",
+    "apply",
+    "assembly",
+    "auto",
+    "bool",
+    "break",
+    "byte",
+    "calldata",
+    "case",
+    "catch",
+    "constant",
+    "constructor",
+    "continue",
+    "contract",
+    "copyof",
+    "days",
+    "default",
+    "define",
+    "delete",
+    "do",
+    "else",
+    "emit",
+    "enum",
+    "error",
+    "ether",
+    "event",
+    "external",
+    "fallback",
+    "false",
+    "final",
+    "for",
+    "function",
+    "gwei",
+    "hours",
+    "if",
+    "immutable",
+    "implements",
+    "import",
+    "in",
+    "indexed",
+    "inline",
+    "interface",
+    "internal",
+    "is",
+    "let",
+    "library",
+    "macro",
+    "mapping",
+    "match",
+    "memory",
+    "minutes",
+    "modifier",
+    "mutable",
+    "new",
+    "null",
+    "of",
+    "override",
+    "partial",
+    "payable",
+    "pragma",
+    "private",
+    "promise",
+    "public",
+    "pure",
+    "receive",
+    "reference",
+    "relocatable",
+    "return",
+    "returns",
+    "revert",
+    "sealed",
+    "seconds",
+    "sizeof",
+    "static",
+    "storage",
+    "string",
+    "struct",
+    "super",
+    "supports",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typedef",
+    "typeof",
+    "unchecked",
+    "unicode",
+    "using",
+    "var",
+    "view",
+    "virtual",
+    "weeks",
+    "wei",
+    "while",
+    "years",
+];
+
+/**
+ * @brief whether a name is a solidity keyword or elementary type name, e.g. `library`, `uint256` or `bytes32`
+ */
+fn is_solidity_keyword(name: &str) -> bool {
+    let digits = |size: &str| size.chars().all(|c| c.is_ascii_digit());
+    let sized_type = match ["ufixed", "fixed", "uint", "int", "bytes"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix).map(|size| (*prefix, size)))
+    {
+        Some(("ufixed" | "fixed", size)) => match size.split_once('x') {
+            Some((m, n)) => digits(m) && digits(n),
+            None => digits(size),
+        },
+        Some((_, size)) => digits(size),
+        None => false,
+    };
+    sized_type || SOLIDITY_KEYWORDS.contains(&name)
+}
+
+/**
+ * @brief check that a name is a legal noir identifier: an ASCII letter or `_` followed by ASCII letters, digits
+ *        or `_`, other than `_` itself and the reserved words
+ */
+pub fn check_noir_identifier(identifier: &str) -> Result<(), String> {
+    let mut chars = identifier.chars();
+    let legal = match chars.next() {
+        Some(first) => {
+            (first.is_ascii_alphabetic() || first == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && identifier != "_"
+        }
+        None => false,
+    };
+    if !legal {
+        return Err(format!("`{identifier}` is not a legal noir identifier"));
+    }
+    if NOIR_RESERVED_WORDS.contains(&identifier) {
+        return Err(format!(
+            "`{identifier}` is a reserved word in noir and cannot be used as a name"
+        ));
+    }
+    Ok(())
+}
+
+/**
+ * @brief convert a parameter name to snake case, e.g. `BN254_Fq` to `bn254_fq` and `BigNumInstance` to `big_num_instance`
+ */
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut r = String::new();
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && previous_lowercase {
+            r.push('_');
+        }
+        previous_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+        r.push(c.to_ascii_lowercase());
+    }
+    r
+}
+
+/**
+ * @brief convert a parameter name to the CamelCase noir expects of struct names, e.g. `BN254_Fq` to `BN254Fq`
+ *        and `big_num` to `BigNum`
+ */
+pub(crate) fn camel_case(name: &str) -> String {
+    let mut r = String::new();
+    for word in name.split('_').filter(|word| !word.is_empty()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            r.push(first.to_ascii_uppercase());
+            r.extend(chars);
+        }
+    }
+    r
+}
+
+/**
+ * @brief the names of the items generated for an instance: the BigNum struct, the BigNumParams global,
+ *        the file written by `--out-dir` and `full` (without extension), and the module of `--lang rust`
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Naming {
+    pub struct_name: String,
+    pub params_name: String,
+    pub file_name: String,
+    pub module_name: String,
+}
+
+impl Naming {
+    /**
+     * @brief the default names of a parameter name: struct `NAME`, global `NAME_PARAMS`, file `NAME`
+     *        and module `snake_case(NAME)`
+     */
+    pub fn new(name: &str) -> Naming {
+        Naming {
+            struct_name: String::from(name),
+            params_name: format!("{name}_PARAMS"),
+            file_name: String::from(name),
+            module_name: snake_case(name),
+        }
+    }
+
+    /**
+     * @brief the default names of `new`, with the struct name converted to CamelCase
+     */
+    pub fn camel_case(name: &str) -> Naming {
+        Naming {
+            struct_name: camel_case(name),
+            ..Naming::new(name)
+        }
+    }
+
+    /**
+     * @brief check that every name is a legal, non-reserved noir identifier, and that the struct and
+     *        the global do not clash
+     */
+    pub fn validate(&self) -> Result<(), String> {
+        for (kind, name) in [
+            ("struct", &self.struct_name),
+            ("params global", &self.params_name),
+            ("file", &self.file_name),
+            ("module", &self.module_name),
+        ] {
+            check_noir_identifier(name).map_err(|error| format!("invalid {kind} name: {error}"))?;
+        }
+        for (kind, name) in [
+            ("struct", &self.struct_name),
+            ("params global", &self.params_name),
+        ] {
+            if IMPORTED_NAMES.contains(&name.as_str()) {
+                return Err(format!(
+                    "invalid {kind} name: `{name}` clashes with the imported `{name}`"
+                ));
+            }
+        }
+        if self.struct_name == self.params_name {
+            return Err(format!(
+                "the struct and the params global are both named `{}`",
+                self.struct_name
+            ));
+        }
+        Ok(())
+    }
+
+    /**
+     * @brief `validate`, and check that the names used by the output of `lang` are not its keywords:
+     *        the module of `Lang::Rust` and the library of `Lang::Solidity`, which is named after the struct
+     */
+    pub fn validate_for(&self, lang: Lang) -> Result<(), String> {
+        self.validate()?;
+        match lang {
+            Lang::Rust if RUST_KEYWORDS.contains(&self.module_name.as_str()) => Err(format!(
+                "invalid module name: `{}` is a keyword in rust",
+                self.module_name
+            )),
+            Lang::Solidity if is_solidity_keyword(&self.struct_name) => Err(format!(
+                "invalid library name: `{}` is a keyword in solidity",
+                self.struct_name
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn test_naming() {
+    assert_eq!(snake_case("BN254_Fq"), "bn254_fq");
+    assert_eq!(snake_case("BigNumInstance"), "big_num_instance");
+    assert_eq!(snake_case("Secp256k1_Fr"), "secp256k1_fr");
+    assert_eq!(snake_case("U2048"), "u2048");
+    assert_eq!(camel_case("BN254_Fq"), "BN254Fq");
+    assert_eq!(camel_case("big_num"), "BigNum");
+
+    let naming = Naming::camel_case("Secp256k1_Fr");
+    assert_eq!(naming.struct_name, "Secp256k1Fr");
+    assert_eq!(naming.params_name, "Secp256k1_Fr_PARAMS");
+    assert_eq!(naming.module_name, "secp256k1_fr");
+    assert!(naming.validate().is_ok());
+
+    for name in ["", "_", "1abc", "a-b", "fn", "Field", "u128", "struct"] {
+        assert!(check_noir_identifier(name).is_err(), "{name}");
+        assert!(Naming::new(name).validate().is_err(), "{name}");
+    }
+    let clash = Naming {
+        params_name: String::from("BN254_Fq"),
+        ..Naming::new("BN254_Fq")
+    };
+    assert!(clash.validate().is_err());
+
+    // the generated code imports these, so a struct or global of the same name does not compile
+    for name in IMPORTED_NAMES {
+        assert!(
+            crate::BN_INSTANCE_IMPORTS.contains(&format!("::{name};")),
+            "{name}"
+        );
+        assert!(Naming::new(name).validate().is_err(), "{name}");
+        let params = Naming {
+            params_name: name.to_string(),
+            ..Naming::new("BN254_Fq")
+        };
+        assert!(params.validate().is_err(), "{name}");
+    }
+
+    // legal noir names that are keywords of the output language
+    for (name, lang) in [
+        ("Async", Lang::Rust),
+        ("Dyn", Lang::Rust),
+        ("library", Lang::Solidity),
+        ("uint256", Lang::Solidity),
+        ("bytes32", Lang::Solidity),
+        ("ufixed128x18", Lang::Solidity),
+    ] {
+        assert!(Naming::new(name).validate().is_ok(), "{name}");
+        assert!(Naming::new(name).validate_for(lang).is_err(), "{name}");
+        assert!(Naming::new(name).validate_for(Lang::Noir).is_ok(), "{name}");
+    }
+    assert!(Naming::new("uint256x").validate_for(Lang::Solidity).is_ok());
+    assert!(Naming::new("BN254_Fq").validate_for(Lang::Rust).is_ok());
+    assert!(Naming::new("BN254_Fq").validate_for(Lang::Solidity).is_ok());
+}
@@ -0,0 +1,188 @@
+use num_bigint::{BigUint, ModInverse};
+use num_traits::{One, Zero};
+
+use crate::bn_limbs_padded;
+use crate::prelude::*;
+
+/**
+ * @brief known-answer values for a modulus, computed in Rust so that the generated noir tests can check
+ *        the BigNumParams arithmetic against an independent reference
+ */
+struct KnownAnswers {
+    a: BigUint,
+    b: BigUint,
+    a_plus_b: BigUint,
+    a_minus_b: BigUint,
+    b_minus_a: BigUint,
+    a_mul_b: BigUint,
+    a_inverse: Option<BigUint>,
+    modulus_minus_one: BigUint,
+}
+
+/**
+ * @brief derive the known-answer values for a modulus.
+ *        operands are fixed fractions of the modulus so the output is deterministic
+ */
+fn compute_known_answers(modulus: &BigUint, has_multiplicative_inverse: bool) -> KnownAnswers {
+    let one = BigUint::one();
+    let a = (modulus * BigUint::from(2u64) / BigUint::from(3u64)) % modulus;
+    let b = (modulus / BigUint::from(7u64) + one.clone()) % modulus;
+    let a_plus_b = (a.clone() + b.clone()) % modulus;
+    let a_minus_b = (a.clone() + modulus - b.clone()) % modulus;
+    let b_minus_a = (b.clone() + modulus - a.clone()) % modulus;
+    let a_mul_b = (a.clone() * b.clone()) % modulus;
+    let mut a_inverse = None;
+    if has_multiplicative_inverse && !a.is_zero() {
+        a_inverse = a
+            .clone()
+            .mod_inverse(modulus)
+            .and_then(|inverse| inverse.to_biguint());
+    }
+    KnownAnswers {
+        a,
+        b,
+        a_plus_b,
+        a_minus_b,
+        b_minus_a,
+        a_mul_b,
+        a_inverse,
+        modulus_minus_one: modulus - one,
+    }
+}
+
+/**
+ * @brief construct a string that represents a noir test module exercising a generated BigNum instance.
+ *        the module is meant to be appended to the output of `compute_bn_instance_string`
+ */
+pub(crate) fn compute_bn_instance_test_string(
+    modulus: &BigUint,
+    num_limbs: usize,
+    name: &str,
+    has_multiplicative_inverse: bool,
+) -> String {
+    let KnownAnswers {
+        a,
+        b,
+        a_plus_b,
+        a_minus_b,
+        b_minus_a,
+        a_mul_b,
+        a_inverse,
+        modulus_minus_one,
+    } = compute_known_answers(modulus, has_multiplicative_inverse);
+    let value = |x: &BigUint| {
+        format!(
+            "{} {{ limbs: {} }}",
+            name,
+            bn_limbs_padded(x, num_limbs).expect("values are reduced modulo the modulus")
+        )
+    };
+
+    let mut r: String = format!(
+        "
+mod tests {{
+    use super::{name};
+    use crate::bignum::BigNum;
+
+    #[test]
+    fn test_add() {{
+        let a = {a};
+        let b = {b};
+        assert(a + b == {a_plus_b});
+    }}
+
+    #[test]
+    fn test_sub() {{
+        let a = {a};
+        let b = {b};
+        assert(a - b == {a_minus_b});
+        assert(b - a == {b_minus_a});
+    }}
+
+    #[test]
+    fn test_mul() {{
+        let a = {a};
+        let b = {b};
+        assert(a * b == {a_mul_b});
+    }}
+
+    #[test]
+    fn test_modulus_minus_one_plus_one() {{
+        let modulus_minus_one = {modulus_minus_one};
+        assert(modulus_minus_one + {name}::one() == {name}::zero());
+    }}
+
+    #[test]
+    fn test_sub_double_modulus_edge_cases() {{
+        let modulus_minus_one = {modulus_minus_one};
+        assert({name}::zero() - {name}::one() == modulus_minus_one);
+        assert({name}::zero() - modulus_minus_one == {name}::one());
+        assert(modulus_minus_one - modulus_minus_one == {name}::zero());
+    }}
+",
+        name = name,
+        a = value(&a),
+        b = value(&b),
+        a_plus_b = value(&a_plus_b),
+        a_minus_b = value(&a_minus_b),
+        b_minus_a = value(&b_minus_a),
+        a_mul_b = value(&a_mul_b),
+        modulus_minus_one = value(&modulus_minus_one),
+    );
+    if let Some(a_inverse) = a_inverse {
+        r += &format!(
+            "
+    #[test]
+    fn test_inverse() {{
+        let a = {a};
+        let a_inverse = {a_inverse};
+        // Safety: the result is checked against the known answer below
+        let computed_inverse = unsafe {{ a.__invmod() }};
+        assert(computed_inverse == a_inverse);
+        assert({name}::one() / a == a_inverse);
+        assert(a * a_inverse == {name}::one());
+    }}
+",
+            name = name,
+            a = value(&a),
+            a_inverse = value(&a_inverse),
+        );
+    }
+    r += "}\n";
+    r
+}
+
+#[test]
+fn test_known_answers() {
+    let modulus = BigUint::from(101u64);
+    let answers = compute_known_answers(&modulus, true);
+    assert_eq!(answers.a, BigUint::from(67u64));
+    assert_eq!(answers.b, BigUint::from(15u64));
+    assert_eq!(answers.a_plus_b, BigUint::from(82u64));
+    assert_eq!(answers.a_minus_b, BigUint::from(52u64));
+    assert_eq!(answers.b_minus_a, BigUint::from(49u64));
+    assert_eq!(answers.a_mul_b, BigUint::from(96u64));
+    assert_eq!(
+        (answers.a * answers.a_inverse.unwrap()) % &modulus,
+        BigUint::one()
+    );
+}
+
+#[test]
+fn test_bn_instance_test_string() {
+    let modulus = BigUint::from(101u64);
+    let tests = compute_bn_instance_test_string(&modulus, 1, "F101", true);
+    assert!(tests.starts_with("\nmod tests {\n    use super::F101;\n"));
+    assert!(tests.contains("let a = F101 { limbs: [0x43] };"));
+    assert!(tests.contains("assert(a * b == F101 { limbs: [0x60] });"));
+    assert!(tests.contains("let modulus_minus_one = F101 { limbs: [0x64] };"));
+    // 67^-1 mod 101 = 98
+    assert!(tests.contains("let a_inverse = F101 { limbs: [0x62] };"));
+    assert!(tests.contains("unsafe { a.__invmod() }"));
+    assert!(tests.contains("assert(F101::one() / a == a_inverse);"));
+    assert!(tests.ends_with("    }\n}\n"));
+
+    let uint = compute_bn_instance_test_string(&BigUint::from(256u64), 1, "U8", false);
+    assert!(uint.contains("fn test_mul()"));
+    assert!(!uint.contains("fn test_inverse()"));
+}
@@ -0,0 +1,329 @@
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, ToPrimitive};
+
+use crate::prelude::*;
+
+/**
+ * @brief largest result of `^` or `**`, in bits. guards against inputs like `2^2^64`
+ */
+const MAX_POWER_BITS: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Number(BigUint),
+    Plus,
+    Minus,
+    Star,
+    Power,
+    LeftParen,
+    RightParen,
+}
+
+/**
+ * @brief read a number literal starting at `chars[start]`, returning the value and the index after the literal.
+ *        `0x`/`0X`, `0b`/`0B` and `0o`/`0O` select the radix, otherwise the literal is base10.
+ *        underscores between digits are ignored. with `join_whitespace`, digits separated only by whitespace belong
+ *        to the same literal (e.g. a value pasted from a line-wrapped document).
+ *        an unprefixed literal containing the letters a-f is ambiguous and rejected
+ */
+fn lex_number(
+    chars: &[char],
+    start: usize,
+    join_whitespace: bool,
+) -> Result<(BigUint, usize), String> {
+    let mut i = start;
+    let radix = match (chars[i], chars.get(i + 1)) {
+        ('0', Some('x' | 'X')) => 16,
+        ('0', Some('b' | 'B')) => 2,
+        ('0', Some('o' | 'O')) => 8,
+        _ => 10,
+    };
+    let prefixed = radix != 10;
+    if prefixed {
+        i += 2;
+    }
+
+    let mut digits = String::new();
+    loop {
+        match chars.get(i) {
+            Some(c) if c.is_ascii_alphanumeric() => digits.push(*c),
+            Some('_') => {
+                let between_digits = chars
+                    .get(i.wrapping_sub(1))
+                    .is_some_and(char::is_ascii_alphanumeric)
+                    && chars.get(i + 1).is_some_and(char::is_ascii_alphanumeric)
+                    && !digits.is_empty();
+                if !between_digits {
+                    return Err(String::from("`_` must be between two digits"));
+                }
+            }
+            Some(c) if c.is_whitespace() && join_whitespace => {
+                let next = chars[i..].iter().position(|c| !c.is_whitespace());
+                match next.map(|offset| chars[i + offset]) {
+                    Some(c) if c.is_ascii_alphanumeric() && !digits.is_empty() => {
+                        i += next.unwrap();
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+            _ => break,
+        }
+        i += 1;
+    }
+
+    if digits.is_empty() {
+        return Err(format!(
+            "`{}` is not followed by any digits",
+            chars[start..start + 2].iter().collect::<String>()
+        ));
+    }
+    if !prefixed
+        && !digits.chars().all(|c| c.is_ascii_digit())
+        && digits.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return Err(format!(
+            "`{digits}` is ambiguous: prefix hexadecimal values with `0x`"
+        ));
+    }
+    if let Some(invalid) = digits.chars().find(|c| !c.is_digit(radix)) {
+        return Err(format!(
+            "invalid digit `{invalid}` in base{radix} literal `{digits}`"
+        ));
+    }
+    let value = BigUint::from_str_radix(&digits, radix)
+        .map_err(|error| format!("could not parse `{digits}`: {error:?}"))?;
+    Ok((value, i))
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    // whitespace only joins digits in a lone number. in an expression `2^255 19` would otherwise read as `2^25519`
+    let join_whitespace = !chars
+        .iter()
+        .any(|c| matches!(c, '+' | '-' | '*' | '^' | '(' | ')'));
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let token = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            c if c.is_ascii_digit() => {
+                if let Some(Token::Number(previous)) = tokens.last() {
+                    return Err(format!(
+                        "missing operator between `{previous}` and the number that follows it; \
+                         separate the digits of a number in an expression with `_`"
+                    ));
+                }
+                let (value, end) = lex_number(&chars, i, join_whitespace)?;
+                i = end;
+                tokens.push(Token::Number(value));
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                i += 1;
+                Token::Power
+            }
+            '*' => Token::Star,
+            '^' => Token::Power,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            c if c.is_ascii_alphabetic() => {
+                let word: String = chars[i..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_alphanumeric())
+                    .collect();
+                if word.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(format!(
+                        "`{word}` is ambiguous: prefix hexadecimal values with `0x`"
+                    ));
+                }
+                return Err(format!("unexpected `{word}`"));
+            }
+            c => return Err(format!("unexpected character `{c}`")),
+        };
+        tokens.push(token);
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+/**
+ * @brief recursive descent evaluator. `^` and `**` bind tightest and are right associative,
+ *        followed by unary `+`/`-`, then `*`, then binary `+`/`-`
+ */
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expr(&mut self) -> Result<BigInt, String> {
+        let mut value = self.term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.next();
+                    value += self.term()?;
+                }
+                Some(Token::Minus) => {
+                    self.next();
+                    value -= self.term()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<BigInt, String> {
+        let mut value = self.unary()?;
+        while self.peek() == Some(&Token::Star) {
+            self.next();
+            value *= self.unary()?;
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<BigInt, String> {
+        match self.peek() {
+            Some(Token::Plus) => {
+                self.next();
+                self.unary()
+            }
+            Some(Token::Minus) => {
+                self.next();
+                Ok(-self.unary()?)
+            }
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<BigInt, String> {
+        let base = self.atom()?;
+        if self.peek() != Some(&Token::Power) {
+            return Ok(base);
+        }
+        self.next();
+        let exponent = self.unary()?;
+        if exponent.sign() == Sign::Minus {
+            return Err(format!("negative exponent {exponent}"));
+        }
+        let exponent = exponent
+            .to_usize()
+            .filter(|exponent| base.bits().saturating_mul(*exponent) <= MAX_POWER_BITS)
+            .ok_or_else(|| format!("{base}^{exponent} exceeds {MAX_POWER_BITS} bits"))?;
+        Ok(num_traits::pow(base, exponent))
+    }
+
+    fn atom(&mut self) -> Result<BigInt, String> {
+        match self.next() {
+            Some(Token::Number(value)) => Ok(BigInt::from_biguint(Sign::Plus, value)),
+            Some(Token::LeftParen) => {
+                let value = self.expr()?;
+                match self.next() {
+                    Some(Token::RightParen) => Ok(value),
+                    _ => Err(String::from("missing `)`")),
+                }
+            }
+            Some(token) => Err(format!("unexpected {}", describe(&token))),
+            None => Err(String::from("unexpected end of input")),
+        }
+    }
+}
+
+fn describe(token: &Token) -> &'static str {
+    match token {
+        Token::Number(_) => "number",
+        Token::Plus => "`+`",
+        Token::Minus => "`-`",
+        Token::Star => "`*`",
+        Token::Power => "`^`",
+        Token::LeftParen => "`(`",
+        Token::RightParen => "`)`",
+    }
+}
+
+/**
+ * @brief parse and evaluate an integer expression such as `2^255 - 19` or `2**384 - 2**128 - 2**96 + 2**32 - 1`.
+ *        numbers are base10 unless prefixed with `0x`, `0b` or `0o`, and may contain `_` separators. whitespace
+ *        between digits is only accepted in an input without operators, which is a single number.
+ *        supported operators are `+`, `-`, `*`, `^` / `**` (exponentiation) and parentheses
+ */
+pub fn evaluate_integer_expression(input: &str) -> Result<BigInt, String> {
+    let tokens =
+        tokenize(input).map_err(|error| format!("could not parse `{}`: {error}", input.trim()))?;
+    if tokens.is_empty() {
+        return Err(String::from("expected an integer, got an empty string"));
+    }
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let value = parser
+        .expr()
+        .and_then(|value| match parser.peek() {
+            None => Ok(value),
+            Some(token) => Err(format!(
+                "unexpected {} after the expression",
+                describe(token)
+            )),
+        })
+        .map_err(|error| format!("could not parse `{}`: {error}", input.trim()))?;
+    Ok(value)
+}
+
+#[test]
+fn test_evaluate_integer_expression() {
+    let value = |input: &str| evaluate_integer_expression(input).map(|v| v.to_string());
+    assert_eq!(value("1234"), Ok(String::from("1234")));
+    assert_eq!(value(" 0XFF "), Ok(String::from("255")));
+    assert_eq!(value("0b1010"), Ok(String::from("10")));
+    assert_eq!(value("0o777"), Ok(String::from("511")));
+    assert_eq!(value("1_000_000"), Ok(String::from("1000000")));
+    assert_eq!(
+        value("0x30644e72 e131a029\n b85045b6"),
+        value("0x30644e72e131a029b85045b6")
+    );
+    // whitespace separated digits are an error in an expression, rather than one number
+    assert!(value("2^255 19").unwrap_err().contains("missing operator"));
+    assert!(value("2**255 - 1 9")
+        .unwrap_err()
+        .contains("missing operator"));
+    assert!(value("(0x30644e72 e131a029)").is_err());
+    assert_eq!(value("2^255_19"), value("2^25519"));
+    assert_eq!(value("-(2 + 3) * 4"), Ok(String::from("-20")));
+    assert_eq!(value("2^3^2"), Ok(String::from("512")));
+    assert_eq!(
+        value("2^255 - 19"),
+        Ok(String::from(
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949"
+        ))
+    );
+    assert_eq!(
+        value("2**384 - 2**128 - 2**96 + 2**32 - 1"),
+        value("0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff")
+    );
+    assert!(value("abcd").unwrap_err().contains("ambiguous"));
+    assert!(value("1234e5").unwrap_err().contains("ambiguous"));
+    assert!(value("0x12g4").is_err());
+    assert!(value("1__0").is_err());
+    assert!(value("0x").is_err());
+    assert!(value("2^").is_err());
+    assert!(value("(1").is_err());
+    assert!(value("").is_err());
+}
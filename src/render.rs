@@ -0,0 +1,244 @@
+use core::fmt::{self, Write};
+
+use num_bigint::BigUint;
+
+use crate::{write_hex_limb, write_hex_limbs, BNInstance, Naming};
+
+/**
+ * @brief output language of a generated instance
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Noir,
+    Rust,
+    Ts,
+    Solidity,
+}
+
+impl Lang {
+    /**
+     * @brief file extension of a generated instance, without the dot
+     */
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Lang::Noir => "nr",
+            Lang::Rust => "rs",
+            Lang::Ts => "ts",
+            Lang::Solidity => "sol",
+        }
+    }
+}
+
+/**
+ * @brief write a rust module with the limbs of a BNInstance as `u128` constants.
+ *        the limbs are the ones written into the noir BigNumParams global by `write_bn_instance_definition`
+ */
+pub(crate) fn write_rust_instance<W: Write>(
+    w: &mut W,
+    num_bits: usize,
+    instance: &BNInstance,
+    modulus: &BigUint,
+    naming: &Naming,
+) -> fmt::Result {
+    let num_limbs = instance.modulus.len();
+    let params_name = &naming.params_name;
+    writeln!(
+        w,
+        "// generated by paramgen from the parameters of the noir {params_name} global, do not edit"
+    )?;
+    writeln!(w)?;
+    writeln!(w, "pub mod {} {{", naming.module_name)?;
+    write!(w, "    pub const MODULUS_HEX: &str = \"")?;
+    write_hex_limb(w, modulus)?;
+    writeln!(w, "\";")?;
+    writeln!(w, "    pub const MOD_BITS: usize = {num_bits};")?;
+    writeln!(w, "    pub const NUM_LIMBS: usize = {num_limbs};")?;
+    writeln!(w, "    pub const LIMB_BITS: usize = 120;")?;
+    writeln!(
+        w,
+        "    pub const HAS_MULTIPLICATIVE_INVERSE: bool = {};",
+        instance.has_multiplicative_inverse
+    )?;
+    for (const_name, limbs) in [
+        ("MODULUS", &instance.modulus),
+        ("DOUBLE_MODULUS", &instance.double_modulus),
+        ("REDC_PARAM", &instance.redc_param),
+    ] {
+        write!(w, "    pub const {const_name}: [u128; {num_limbs}] = [")?;
+        write_hex_limbs(w, limbs)?;
+        writeln!(w, "];")?;
+    }
+    writeln!(w, "}}")
+}
+
+/**
+ * @brief write a typescript module with the limbs of a BNInstance as `bigint` constants, and `toLimbs` and
+ *        `fromLimbs` helpers that convert between a `bigint` and the hex string limbs noir_js takes as input
+ */
+pub(crate) fn write_ts_instance<W: Write>(
+    w: &mut W,
+    num_bits: usize,
+    instance: &BNInstance,
+    modulus: &BigUint,
+    naming: &Naming,
+) -> fmt::Result {
+    let num_limbs = instance.modulus.len();
+    let params_name = &naming.params_name;
+    writeln!(
+        w,
+        "// generated by paramgen from the parameters of the noir {params_name} global, do not edit"
+    )?;
+    writeln!(w)?;
+    write!(w, "export const MODULUS_HEX = \"")?;
+    write_hex_limb(w, modulus)?;
+    writeln!(w, "\";")?;
+    writeln!(w, "export const MOD_BITS = {num_bits};")?;
+    writeln!(w, "export const NUM_LIMBS = {num_limbs};")?;
+    writeln!(w, "export const LIMB_BITS = 120;")?;
+    writeln!(
+        w,
+        "export const HAS_MULTIPLICATIVE_INVERSE = {};",
+        instance.has_multiplicative_inverse
+    )?;
+    for (const_name, limbs) in [
+        ("MODULUS", &instance.modulus),
+        ("DOUBLE_MODULUS", &instance.double_modulus),
+        ("REDC_PARAM", &instance.redc_param),
+    ] {
+        write!(w, "export const {const_name}: readonly bigint[] = [")?;
+        for (i, limb) in limbs.iter().enumerate() {
+            if i > 0 {
+                w.write_str(", ")?;
+            }
+            write_hex_limb(w, limb)?;
+            w.write_char('n')?;
+        }
+        writeln!(w, "];")?;
+    }
+    w.write_str(TS_LIMB_HELPERS)
+}
+
+/**
+ * @brief the limb conversion helpers of `write_ts_instance`, written in terms of its NUM_LIMBS and LIMB_BITS
+ */
+const TS_LIMB_HELPERS: &str = r#"
+const LIMB_MASK = (1n << BigInt(LIMB_BITS)) - 1n;
+
+/**
+ * split a value into NUM_LIMBS limbs of LIMB_BITS bits, least significant first, as hex strings.
+ * throws a RangeError if the value is negative or does not fit
+ */
+export function toLimbs(value: bigint): string[] {
+  if (value < 0n || value >> BigInt(NUM_LIMBS * LIMB_BITS) !== 0n) {
+    throw new RangeError(`${value} does not fit in ${NUM_LIMBS} limbs of ${LIMB_BITS} bits`);
+  }
+  const limbs: string[] = [];
+  for (let i = 0; i < NUM_LIMBS; i++) {
+    limbs.push("0x" + ((value >> BigInt(i * LIMB_BITS)) & LIMB_MASK).toString(16));
+  }
+  return limbs;
+}
+
+/**
+ * combine NUM_LIMBS limbs of LIMB_BITS bits, least significant first, into a value.
+ * throws a RangeError if the number of limbs is wrong or a limb is out of range
+ */
+export function fromLimbs(limbs: readonly (bigint | number | string)[]): bigint {
+  if (limbs.length !== NUM_LIMBS) {
+    throw new RangeError(`expected ${NUM_LIMBS} limbs, got ${limbs.length}`);
+  }
+  let value = 0n;
+  for (let i = NUM_LIMBS - 1; i >= 0; i--) {
+    const limb = BigInt(limbs[i]);
+    if (limb < 0n || limb > LIMB_MASK) {
+      throw new RangeError(`limb ${i} is not a ${LIMB_BITS}-bit value`);
+    }
+    value = (value << BigInt(LIMB_BITS)) | limb;
+  }
+  return value;
+}
+"#;
+
+/**
+ * @brief write a solidity library with a `uint256` constant per limb of a BNInstance, and `toLimbs` functions
+ *        that pack a `uint256` or big-endian `bytes` value into the 120-bit limbs the circuit takes as public inputs
+ */
+pub(crate) fn write_solidity_instance<W: Write>(
+    w: &mut W,
+    num_bits: usize,
+    instance: &BNInstance,
+    modulus: &BigUint,
+    naming: &Naming,
+) -> fmt::Result {
+    let num_limbs = instance.modulus.len();
+    let params_name = &naming.params_name;
+    let name = &naming.struct_name;
+    writeln!(w, "// SPDX-License-Identifier: Apache-2.0")?;
+    writeln!(w, "pragma solidity ^0.8.0;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "// generated by paramgen from the parameters of the noir {params_name} global, do not edit"
+    )?;
+    writeln!(w, "library {name} {{")?;
+    writeln!(w, "    uint256 internal constant MOD_BITS = {num_bits};")?;
+    writeln!(w, "    uint256 internal constant NUM_LIMBS = {num_limbs};")?;
+    writeln!(w, "    uint256 internal constant LIMB_BITS = 120;")?;
+    writeln!(
+        w,
+        "    uint256 internal constant LIMB_MASK = (1 << LIMB_BITS) - 1;"
+    )?;
+    writeln!(
+        w,
+        "    bool internal constant HAS_MULTIPLICATIVE_INVERSE = {};",
+        instance.has_multiplicative_inverse
+    )?;
+    // padded to 64 digits, since solidity rejects 39 to 41 digit hex literals that are not checksummed addresses
+    if modulus.bits() <= 256 {
+        writeln!(
+            w,
+            "    uint256 internal constant MODULUS = 0x{:0>64};",
+            modulus.to_str_radix(16)
+        )?;
+    }
+    for (const_name, limbs) in [
+        ("MODULUS", &instance.modulus),
+        ("DOUBLE_MODULUS", &instance.double_modulus),
+        ("REDC_PARAM", &instance.redc_param),
+    ] {
+        for (i, limb) in limbs.iter().enumerate() {
+            write!(w, "    uint256 internal constant {const_name}_{i} = ")?;
+            write_hex_limb(w, limb)?;
+            writeln!(w, ";")?;
+        }
+    }
+    write!(
+        w,
+        r#"
+    /// @notice split a value into NUM_LIMBS limbs of LIMB_BITS bits, least significant first
+    function toLimbs(uint256 value) internal pure returns (uint256[{num_limbs}] memory limbs) {{
+        for (uint256 i = 0; i < NUM_LIMBS && value != 0; i++) {{
+            limbs[i] = value & LIMB_MASK;
+            value >>= LIMB_BITS;
+        }}
+        require(value == 0, "{name}: value does not fit");
+    }}
+
+    /// @notice split a big-endian value into NUM_LIMBS limbs of LIMB_BITS bits, least significant first.
+    ///         a limb is 15 bytes, so each byte goes into a single limb
+    function toLimbs(bytes memory value) internal pure returns (uint256[{num_limbs}] memory limbs) {{
+        uint256 length = value.length;
+        for (uint256 i = 0; i < length; i++) {{
+            uint256 position = length - 1 - i;
+            uint256 digit = uint8(value[i]);
+            if (position >= NUM_LIMBS * 15) {{
+                require(digit == 0, "{name}: value does not fit");
+            }} else {{
+                limbs[position / 15] |= digit << (8 * (position % 15));
+            }}
+        }}
+    }}
+}}
+"#
+    )
+}
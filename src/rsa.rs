@@ -0,0 +1,226 @@
+use num_bigint::BigUint;
+
+use crate::hash::HashAlgorithm;
+use crate::prelude::*;
+use crate::{
+    compute_barrett_reduction_parameter_with_bits, format_limbs_json, split_into_exact_limbs,
+};
+
+/**
+ * @brief smallest RSA modulus supported by `rsa_witness`, in bits
+ */
+pub const RSA_MIN_BITS: usize = 1024;
+
+/**
+ * @brief largest RSA modulus supported by `rsa_witness`, in bits
+ */
+pub const RSA_MAX_BITS: usize = 4096;
+
+/**
+ * @brief the inputs of a circuit that verifies an RSA PKCS#1 v1.5 signature, checked by `rsa_witness`
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaWitness {
+    pub bits: usize,
+    pub hash_algorithm: HashAlgorithm,
+    pub modulus: BigUint,
+    pub redc_param: BigUint,
+    pub exponent: BigUint,
+    pub signature: BigUint,
+    pub digest: Vec<u8>,
+    pub encoded_message: BigUint,
+}
+
+/**
+ * @brief the DER encoding of a DigestInfo without the digest, from the notes of RFC 8017 section 9.2
+ */
+fn digest_info_prefix(hash_algorithm: HashAlgorithm) -> &'static [u8] {
+    match hash_algorithm {
+        HashAlgorithm::Sha256 => &[
+            0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+            0x01, 0x05, 0x00, 0x04, 0x20,
+        ],
+        HashAlgorithm::Sha384 => &[
+            0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+            0x02, 0x05, 0x00, 0x04, 0x30,
+        ],
+        HashAlgorithm::Sha512 => &[
+            0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+            0x03, 0x05, 0x00, 0x04, 0x40,
+        ],
+    }
+}
+
+/**
+ * @brief EMSA-PKCS1-v1_5 encoding (RFC 8017 section 9.2) of a digest for a modulus of `num_bytes` bytes,
+ *        i.e. 0x00 0x01 0xff .. 0xff 0x00 DigestInfo
+ */
+pub fn pkcs1_v15_encode(
+    digest: &[u8],
+    hash_algorithm: HashAlgorithm,
+    num_bytes: usize,
+) -> Result<BigUint, String> {
+    if digest.len() != hash_algorithm.output_len() {
+        return Err(format!(
+            "a {} digest is {} bytes, got {}",
+            hash_algorithm.name(),
+            hash_algorithm.output_len(),
+            digest.len()
+        ));
+    }
+    let prefix = digest_info_prefix(hash_algorithm);
+    let digest_info_len = prefix.len() + digest.len();
+    // at least 8 bytes of 0xff padding
+    if num_bytes < digest_info_len + 11 {
+        return Err(format!(
+            "a {num_bytes}-byte modulus is too short for a {} signature",
+            hash_algorithm.name()
+        ));
+    }
+    let mut encoded = vec![0xffu8; num_bytes];
+    encoded[0] = 0x00;
+    encoded[1] = 0x01;
+    encoded[num_bytes - digest_info_len - 1] = 0x00;
+    encoded[num_bytes - digest_info_len..num_bytes - digest.len()].copy_from_slice(prefix);
+    encoded[num_bytes - digest.len()..].copy_from_slice(digest);
+    Ok(BigUint::from_bytes_be(&encoded))
+}
+
+/**
+ * @brief verify an RSA PKCS#1 v1.5 signature of a digest and collect the inputs of a circuit that verifies it.
+ *        the modulus must have exactly `bits` bits, the width the circuit's BigNumParams are generated for
+ */
+pub fn rsa_witness(
+    bits: usize,
+    modulus: &BigUint,
+    exponent: &BigUint,
+    signature: &BigUint,
+    digest: &[u8],
+    hash_algorithm: HashAlgorithm,
+) -> Result<RsaWitness, String> {
+    if !(RSA_MIN_BITS..=RSA_MAX_BITS).contains(&bits) {
+        return Err(format!(
+            "RSA width must be between {RSA_MIN_BITS} and {RSA_MAX_BITS} bits, got {bits}"
+        ));
+    }
+    if modulus.bits() != bits {
+        return Err(format!(
+            "the modulus has {} bits, expected {bits}",
+            modulus.bits()
+        ));
+    }
+    if signature >= modulus {
+        return Err(String::from("the signature is not less than the modulus"));
+    }
+    let redc_param = compute_barrett_reduction_parameter_with_bits(modulus, bits);
+    split_into_exact_limbs(&redc_param, bits.div_ceil(120), 120)
+        .map_err(|error| format!("unsupported RSA width {bits}: {error}"))?;
+    let encoded_message = pkcs1_v15_encode(digest, hash_algorithm, bits.div_ceil(8))?;
+    if signature.modpow(exponent, modulus) != encoded_message {
+        return Err(format!(
+            "signature verification failed: s^e mod n is not the PKCS#1 v1.5 encoding of the {} digest",
+            hash_algorithm.name()
+        ));
+    }
+    Ok(RsaWitness {
+        bits,
+        hash_algorithm,
+        modulus: modulus.clone(),
+        redc_param,
+        exponent: exponent.clone(),
+        signature: signature.clone(),
+        digest: digest.to_vec(),
+        encoded_message,
+    })
+}
+
+impl RsaWitness {
+    /**
+     * @brief a Prover.toml with the 120-bit limbs of the modulus, redc parameter, signature and encoded message
+     *        as hex strings, the exponent, and the digest bytes
+     */
+    pub fn to_prover_toml(&self) -> String {
+        let num_limbs = self.bits.div_ceil(120);
+        let limbs = |value: &BigUint| {
+            format_limbs_json(
+                &split_into_exact_limbs(value, num_limbs, 120)
+                    .expect("rsa_witness checked that every value fits"),
+            )
+        };
+        let digest: Vec<String> = self.digest.iter().map(|byte| byte.to_string()).collect();
+        let mut r = format!(
+            "# RSA-{} PKCS#1 v1.5 signature with {}, verified by paramgen rsa-witness\n",
+            self.bits,
+            self.hash_algorithm.name()
+        );
+        r += &format!(
+            "# BigNum values are {num_limbs} limbs of 120 bits, least significant first\n"
+        );
+        r += &format!("modulus = {}\n", limbs(&self.modulus));
+        r += &format!("redc_param = {}\n", limbs(&self.redc_param));
+        r += &format!("signature = {}\n", limbs(&self.signature));
+        r += &format!("encoded_message = {}\n", limbs(&self.encoded_message));
+        r += &format!("exponent = \"{}\"\n", self.exponent);
+        r += &format!("message_hash = [{}]\n", digest.join(", "));
+        r
+    }
+}
+
+#[test]
+fn test_rsa_witness() {
+    let parse = |s: &str| crate::try_bignum_from_string(s).unwrap();
+    // a 1024-bit key and a signature of "hello noir" made with `openssl dgst -sha256 -sign`
+    let modulus = parse(
+        "0xc66d18fb20b3b44ed268db52e06ff27e21f9b778a509d43fb69b9d12b0fde7066d97dd567d909f59263fcd17f588dbe0\
+         c0becce60ca1125828dcd96a2ddde3a457f3b4166b47dbe7caafa35b68453f1c0d2c73a69002b3d529044092fb990764\
+         515ffd286e28e978632bab5e0fe2592415f5967718480052a3b1acad6afa33dd",
+    );
+    let signature = parse(
+        "0x88117ae1866f900301ab9bcb50828845844b0f0dab4c6900a75077fa9004f3037818a59afdb5216f12ab1d0374ea2709\
+         6666f2d5dd6758673d708230ffec1091339a6370faa2e428e5927214fab3bf54d4887db70926fcfa05840b7aadd904fe\
+         39532b3cf4e066f74f61fff99bd22d4b69243fe4823fb66cde3d44fa57c82823",
+    );
+    let exponent = BigUint::from(65537u64);
+    let digest = HashAlgorithm::Sha256.digest(b"hello noir");
+
+    let witness = rsa_witness(
+        1024,
+        &modulus,
+        &exponent,
+        &signature,
+        &digest,
+        HashAlgorithm::Sha256,
+    )
+    .unwrap();
+    let toml = witness.to_prover_toml();
+    for (key, value) in [
+        ("modulus", &modulus),
+        ("signature", &signature),
+        ("encoded_message", &witness.encoded_message),
+    ] {
+        let limbs = crate::limbs_from_prover_toml(&toml, key).unwrap();
+        assert_eq!(limbs.len(), 9);
+        assert_eq!(&crate::integer_from_limbs(&limbs).unwrap(), value);
+    }
+    assert!(toml.contains("exponent = \"65537\"\n"));
+
+    let tampered = &signature + BigUint::from(1u64);
+    assert!(rsa_witness(
+        1024,
+        &modulus,
+        &exponent,
+        &tampered,
+        &digest,
+        HashAlgorithm::Sha256
+    )
+    .is_err());
+    assert!(rsa_witness(
+        2048,
+        &modulus,
+        &exponent,
+        &signature,
+        &digest,
+        HashAlgorithm::Sha256
+    )
+    .is_err());
+}
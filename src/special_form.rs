@@ -0,0 +1,295 @@
+use num_bigint::{BigUint, ModInverse};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+use crate::prelude::*;
+use crate::{format_limbs, split_into_120_bit_limbs, write_hex_limb};
+
+/**
+ * @brief largest c, in bits, of a pseudo-Mersenne modulus 2^k - c. c must also be at most k/2 bits
+ */
+const PSEUDO_MERSENNE_MAX_C_BITS: usize = 64;
+
+/**
+ * @brief smallest number of signed power-of-two terms of a Solinas modulus. a single power of two is not one
+ */
+const SOLINAS_MIN_TERMS: usize = 2;
+
+/**
+ * @brief largest number of signed power-of-two terms of a Solinas modulus
+ */
+const SOLINAS_MAX_TERMS: usize = 8;
+
+/**
+ * @brief smallest t for which a modulus p = -1 mod 2^t is reported as Montgomery-friendly
+ */
+const MONTGOMERY_FRIENDLY_MIN_BITS: usize = 64;
+
+/**
+ * @brief a modulus of the form 2^k - c with small c
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PseudoMersenne {
+    pub k: usize,
+    pub c: BigUint,
+}
+
+/**
+ * @brief a term +-2^exponent of a Solinas modulus
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolinasTerm {
+    pub exponent: usize,
+    pub negative: bool,
+}
+
+/**
+ * @brief a modulus p = -1 mod 2^trailing_ones. `inverse` is the Montgomery constant -p^-1 mod 2^120,
+ *        which is 1 when trailing_ones >= 120
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryFriendly {
+    pub trailing_ones: usize,
+    pub inverse: BigUint,
+}
+
+/**
+ * @brief the special forms detected for a modulus. a modulus can have several forms, e.g. 2^255 - 19
+ *        is both pseudo-Mersenne and Solinas
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SpecialForms {
+    pub pseudo_mersenne: Option<PseudoMersenne>,
+    pub solinas: Option<Vec<SolinasTerm>>,
+    pub montgomery_friendly: Option<MontgomeryFriendly>,
+}
+
+/**
+ * @brief the non-adjacent form of a value, most significant term first.
+ *        it has the fewest nonzero signed binary digits of any representation
+ */
+fn non_adjacent_form(value: &BigUint) -> Vec<SolinasTerm> {
+    let one = BigUint::one();
+    let three = BigUint::from(3u64);
+    let mut n = value.clone();
+    let mut terms = Vec::new();
+    let mut exponent = 0;
+    while !n.is_zero() {
+        if n.is_odd() {
+            let negative = (&n & &three) == three;
+            if negative {
+                n += &one;
+            } else {
+                n -= &one;
+            }
+            terms.push(SolinasTerm { exponent, negative });
+        }
+        n >>= 1;
+        exponent += 1;
+    }
+    terms.reverse();
+    terms
+}
+
+fn trailing_ones(value: &BigUint) -> usize {
+    (value + BigUint::one())
+        .trailing_zeros()
+        .expect("value + 1 is not zero")
+}
+
+/**
+ * @brief detect the pseudo-Mersenne, Solinas and Montgomery-friendly forms of a modulus
+ */
+pub fn detect_special_forms(modulus: &BigUint) -> SpecialForms {
+    let mut forms = SpecialForms::default();
+    if modulus.bits() < 2 {
+        return forms;
+    }
+
+    let k = modulus.bits();
+    let c = (BigUint::one() << k) - modulus;
+    if !c.is_zero() && c.bits() <= PSEUDO_MERSENNE_MAX_C_BITS.min(k / 2) {
+        forms.pseudo_mersenne = Some(PseudoMersenne { k, c });
+    }
+
+    let terms = non_adjacent_form(modulus);
+    if (SOLINAS_MIN_TERMS..=SOLINAS_MAX_TERMS).contains(&terms.len()) {
+        forms.solinas = Some(terms);
+    }
+
+    let t = trailing_ones(modulus);
+    if t >= MONTGOMERY_FRIENDLY_MIN_BITS {
+        let limb_modulus = BigUint::one() << 120usize;
+        let inverse = modulus
+            .clone()
+            .mod_inverse(&limb_modulus)
+            .and_then(|inverse| inverse.to_biguint())
+            .expect("an odd modulus is invertible mod 2^120");
+        forms.montgomery_friendly = Some(MontgomeryFriendly {
+            trailing_ones: t,
+            inverse: (&limb_modulus - inverse) % &limb_modulus,
+        });
+    }
+    forms
+}
+
+/**
+ * @brief format Solinas terms as an expression, e.g. `2^256 - 2^224 + 2^192 + 2^96 - 1`
+ */
+pub fn format_solinas_terms(terms: &[SolinasTerm]) -> String {
+    let mut r = String::new();
+    for (i, term) in terms.iter().enumerate() {
+        if i > 0 {
+            r += if term.negative { " - " } else { " + " };
+        } else if term.negative {
+            r += "-";
+        }
+        if term.exponent == 0 {
+            r += "1";
+        } else {
+            r += &format!("2^{}", term.exponent);
+        }
+    }
+    r
+}
+
+impl SpecialForms {
+    pub fn is_empty(&self) -> bool {
+        self.pseudo_mersenne.is_none()
+            && self.solinas.is_none()
+            && self.montgomery_friendly.is_none()
+    }
+
+    /**
+     * @brief human readable description of each detected form, one per line
+     */
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Some(PseudoMersenne { k, c }) = &self.pseudo_mersenne {
+            lines.push(format!(
+                "pseudo-Mersenne: 2^{k} - c with c = 0x{} ({} bits)",
+                c.to_str_radix(16),
+                c.bits()
+            ));
+        }
+        if let Some(terms) = &self.solinas {
+            lines.push(format!(
+                "Solinas: {} ({} terms)",
+                format_solinas_terms(terms),
+                terms.len()
+            ));
+        }
+        if let Some(MontgomeryFriendly {
+            trailing_ones,
+            inverse,
+        }) = &self.montgomery_friendly
+        {
+            lines.push(format!(
+                "Montgomery-friendly: p = -1 mod 2^{trailing_ones}, -p^-1 mod 2^120 = 0x{}",
+                inverse.to_str_radix(16)
+            ));
+        }
+        if lines.is_empty() {
+            lines.push(String::from("no special form detected"));
+        }
+        lines
+    }
+
+    /**
+     * @brief noir globals with the constants of each detected form, for specialized reduction code.
+     *        c is split into 120-bit limbs, Solinas terms are given as parallel exponent and sign arrays
+     */
+    pub fn noir_constants(&self, name: &str) -> String {
+        let mut r = String::new();
+        if let Some(PseudoMersenne { k, c }) = &self.pseudo_mersenne {
+            let c_limbs = split_into_120_bit_limbs(c, c.bits());
+            r += &format!("pub global {name}_PSEUDO_MERSENNE_K: u32 = {k};\n");
+            r += &format!(
+                "pub global {name}_PSEUDO_MERSENNE_C: [u128; {}] = {};\n",
+                c_limbs.len(),
+                format_limbs(&c_limbs)
+            );
+        }
+        if let Some(terms) = &self.solinas {
+            let exponents: Vec<String> = terms.iter().map(|t| t.exponent.to_string()).collect();
+            let signs: Vec<&str> = terms
+                .iter()
+                .map(|t| if t.negative { "-1" } else { "1" })
+                .collect();
+            r += &format!(
+                "pub global {name}_SOLINAS_EXPONENTS: [u32; {}] = [{}];\n",
+                terms.len(),
+                exponents.join(", ")
+            );
+            r += &format!(
+                "pub global {name}_SOLINAS_SIGNS: [i8; {}] = [{}];\n",
+                terms.len(),
+                signs.join(", ")
+            );
+        }
+        if let Some(MontgomeryFriendly {
+            trailing_ones,
+            inverse,
+        }) = &self.montgomery_friendly
+        {
+            r += &format!("pub global {name}_MONTGOMERY_TRAILING_ONES: u32 = {trailing_ones};\n");
+            r += &format!("pub global {name}_MONTGOMERY_INV: u128 = ");
+            write_hex_limb(&mut r, inverse).expect("writing to a String cannot fail");
+            r += ";\n";
+        }
+        r
+    }
+}
+
+#[test]
+fn test_detect_special_forms() {
+    let parse = |s: &str| crate::try_bignum_from_string(s).unwrap();
+
+    let ed25519 = detect_special_forms(&parse("2^255 - 19"));
+    assert_eq!(
+        ed25519.pseudo_mersenne,
+        Some(PseudoMersenne {
+            k: 255,
+            c: BigUint::from(19u64)
+        })
+    );
+    assert!(ed25519.montgomery_friendly.is_none());
+
+    let secp256k1 = detect_special_forms(&parse("2^256 - 2^32 - 977"));
+    assert_eq!(secp256k1.pseudo_mersenne.unwrap().c, parse("2^32 + 977"));
+
+    let p256 = detect_special_forms(&parse("2^256 - 2^224 + 2^192 + 2^96 - 1"));
+    assert!(p256.pseudo_mersenne.is_none());
+    assert_eq!(
+        format_solinas_terms(&p256.solinas.clone().unwrap()),
+        "2^256 - 2^224 + 2^192 + 2^96 - 1"
+    );
+    let montgomery = p256.montgomery_friendly.clone().unwrap();
+    assert_eq!(montgomery.trailing_ones, 96);
+    let limb_modulus = BigUint::one() << 120usize;
+    assert_eq!(
+        (parse("2^256 - 2^224 + 2^192 + 2^96 - 1") * montgomery.inverse + BigUint::one())
+            % limb_modulus,
+        BigUint::zero()
+    );
+    assert!(p256
+        .noir_constants("P256")
+        .contains("pub global P256_SOLINAS_SIGNS: [i8; 5] = [1, -1, 1, 1, -1];\n"));
+
+    let bn254 = detect_special_forms(&parse(
+        "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+    ));
+    assert!(bn254.is_empty());
+    assert_eq!(bn254.noir_constants("BN254"), "");
+
+    // a power of two has a single term and is not a Solinas modulus
+    for power_of_two in ["2", "2^64", "2^256"] {
+        assert!(detect_special_forms(&parse(power_of_two)).solinas.is_none());
+    }
+    assert_eq!(
+        detect_special_forms(&parse("2^64 + 1"))
+            .solinas
+            .map(|terms| terms.len()),
+        Some(2)
+    );
+}
@@ -0,0 +1,287 @@
+use core::fmt::Write;
+
+use num_bigint::{BigUint, ModInverse, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rand::RngCore;
+
+use crate::prelude::*;
+use crate::{
+    format_limbs_json, instance_num_limbs, is_prime_with_rng, seeded_rng, split_into_exact_limbs,
+    write_limbs, DEFAULT_SEED,
+};
+
+/**
+ * @brief random operands and the results noir-bignum must compute for them, reduced modulo the modulus.
+ *        `a_div_b` and `sqrt_a` are only defined for a prime modulus, and `sqrt_a` only if `a` is a square
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestVector {
+    pub a: BigUint,
+    pub b: BigUint,
+    pub e: BigUint,
+    pub a_plus_b: BigUint,
+    pub a_minus_b: BigUint,
+    pub a_mul_b: BigUint,
+    pub a_div_b: Option<BigUint>,
+    pub a_pow_e: BigUint,
+    pub sqrt_a: Option<BigUint>,
+}
+
+/**
+ * @brief test vectors for a BigNumParams<N, MOD_BITS> instance, see `test_vectors_with_rng`
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestVectors {
+    pub modulus: BigUint,
+    pub num_bits: usize,
+    pub has_multiplicative_inverse: bool,
+    pub vectors: Vec<TestVector>,
+}
+
+/**
+ * @brief the square root of `a` modulo an odd prime by Tonelli-Shanks, if `a` is a square.
+ *        of the two roots, the smaller one is returned
+ */
+fn sqrt_mod_prime(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let one = BigUint::one();
+    if a.is_zero() {
+        return Some(BigUint::zero());
+    }
+    let p_minus_one = p - &one;
+    let legendre = |x: &BigUint| x.modpow(&(&p_minus_one >> 1usize), p);
+    if legendre(a) != one {
+        return None;
+    }
+    // p - 1 = q * 2^s with q odd
+    let mut q = p_minus_one.clone();
+    let mut s = 0usize;
+    while q.is_even() {
+        q >>= 1usize;
+        s += 1;
+    }
+    let mut z = BigUint::from(2u64);
+    while legendre(&z) != p_minus_one {
+        z += 1u64;
+    }
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + &one) >> 1usize), p);
+    while t != one {
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = (&t_pow * &t_pow) % p;
+            i += 1;
+        }
+        let mut b = c.clone();
+        for _ in 0..m - i - 1 {
+            b = (&b * &b) % p;
+        }
+        m = i;
+        c = (&b * &b) % p;
+        t = (t * &c) % p;
+        r = (r * b) % p;
+    }
+    let other = p - &r;
+    Some(r.min(other))
+}
+
+/**
+ * @brief `count` test vectors for a modulus with `count` random operands a, b (nonzero) and e below the modulus,
+ *        drawn from `seeded_rng(DEFAULT_SEED)`
+ */
+pub fn test_vectors(modulus: &BigUint, num_bits: usize, count: usize) -> TestVectors {
+    test_vectors_with_rng(modulus, num_bits, count, &mut seeded_rng(DEFAULT_SEED))
+}
+
+/**
+ * @brief `count` test vectors for a modulus with `count` random operands a, b (nonzero) and e below the modulus,
+ *        drawn from `rng` after the primality test. `num_bits` is the MOD_BITS of the instance, which sets the
+ *        limb count of the output
+ */
+pub fn test_vectors_with_rng<R: RngCore + ?Sized>(
+    modulus: &BigUint,
+    num_bits: usize,
+    count: usize,
+    rng: &mut R,
+) -> TestVectors {
+    let one = BigUint::one();
+    let has_multiplicative_inverse = is_prime_with_rng(modulus, rng);
+    let vectors = (0..count)
+        .map(|_| {
+            let a = rng.gen_biguint_below(modulus);
+            let b = rng.gen_biguint_range(&one, modulus);
+            let e = rng.gen_biguint_below(modulus);
+            let mut a_div_b = None;
+            let mut sqrt_a = None;
+            if has_multiplicative_inverse {
+                a_div_b = b
+                    .clone()
+                    .mod_inverse(modulus)
+                    .and_then(|inverse| inverse.to_biguint())
+                    .map(|inverse| (&a * inverse) % modulus);
+                if modulus.is_odd() {
+                    sqrt_a = sqrt_mod_prime(&a, modulus);
+                }
+            }
+            TestVector {
+                a_plus_b: (&a + &b) % modulus,
+                a_minus_b: (&a + modulus - &b) % modulus,
+                a_mul_b: (&a * &b) % modulus,
+                a_div_b,
+                a_pow_e: a.modpow(&e, modulus),
+                sqrt_a,
+                a,
+                b,
+                e,
+            }
+        })
+        .collect();
+    TestVectors {
+        modulus: modulus.clone(),
+        num_bits,
+        has_multiplicative_inverse,
+        vectors,
+    }
+}
+
+/**
+ * @brief reads a field of a TestVector, `None` where the value is not defined
+ */
+type FieldAccessor = fn(&TestVector) -> Option<&BigUint>;
+
+/**
+ * @brief the fields of a TestVector by name
+ */
+const TEST_VECTOR_FIELDS: [(&str, FieldAccessor); 9] = [
+    ("a", |vector| Some(&vector.a)),
+    ("b", |vector| Some(&vector.b)),
+    ("e", |vector| Some(&vector.e)),
+    ("a_plus_b", |vector| Some(&vector.a_plus_b)),
+    ("a_minus_b", |vector| Some(&vector.a_minus_b)),
+    ("a_mul_b", |vector| Some(&vector.a_mul_b)),
+    ("a_div_b", |vector| vector.a_div_b.as_ref()),
+    ("a_pow_e", |vector| Some(&vector.a_pow_e)),
+    ("sqrt_a", |vector| vector.sqrt_a.as_ref()),
+];
+
+impl TestVectors {
+    fn limbs(&self, value: &BigUint) -> Vec<BigUint> {
+        split_into_exact_limbs(value, instance_num_limbs(&self.modulus, self.num_bits), 120)
+            .expect("values are reduced modulo the modulus")
+    }
+
+    /**
+     * @brief noir globals `{name}_VECTORS_{FIELD}` holding the limbs of each field of every vector.
+     *        `{name}_VECTORS_HAS_SQRT_A` flags the vectors whose `sqrt_a` is defined, the others are zero
+     */
+    pub fn to_noir(&self, name: &str) -> String {
+        let num_limbs = instance_num_limbs(&self.modulus, self.num_bits);
+        let count = self.vectors.len();
+        let mut r = format!(
+            "// {count} test vectors for {name}, computed by paramgen vectors. values are limbs of {name}_PARAMS\n"
+        );
+        for (field, value_of) in TEST_VECTOR_FIELDS {
+            // division and square roots are only defined for a prime modulus
+            if !self.has_multiplicative_inverse && matches!(field, "a_div_b" | "sqrt_a") {
+                continue;
+            }
+            write!(
+                r,
+                "pub global {name}_VECTORS_{}: [[u128; {num_limbs}]; {count}] = [",
+                field.to_ascii_uppercase()
+            )
+            .expect("writing to a String cannot fail");
+            for (i, vector) in self.vectors.iter().enumerate() {
+                if i > 0 {
+                    r += ", ";
+                }
+                let value = value_of(vector).cloned().unwrap_or_else(BigUint::zero);
+                write_limbs(&mut r, &self.limbs(&value)).expect("writing to a String cannot fail");
+            }
+            r += "];\n";
+        }
+        if self.has_multiplicative_inverse {
+            let has_sqrt: Vec<String> = self
+                .vectors
+                .iter()
+                .map(|vector| vector.sqrt_a.is_some().to_string())
+                .collect();
+            r += &format!(
+                "pub global {name}_VECTORS_HAS_SQRT_A: [bool; {count}] = [{}];\n",
+                has_sqrt.join(", ")
+            );
+        }
+        r
+    }
+
+    /**
+     * @brief a JSON array with an object per vector, holding the limbs of each field as hex strings.
+     *        `a_div_b` and `sqrt_a` are null where they are not defined
+     */
+    pub fn to_json(&self) -> serde_json::Value {
+        let vectors = self
+            .vectors
+            .iter()
+            .map(|vector| {
+                let mut object = serde_json::Map::new();
+                for (field, value_of) in TEST_VECTOR_FIELDS {
+                    let value = match value_of(vector) {
+                        Some(value) => serde_json::from_str(&format_limbs_json(&self.limbs(value)))
+                            .expect("format_limbs_json writes a JSON array"),
+                        None => serde_json::Value::Null,
+                    };
+                    object.insert(String::from(field), value);
+                }
+                serde_json::Value::Object(object)
+            })
+            .collect();
+        serde_json::Value::Array(vectors)
+    }
+}
+
+#[test]
+fn test_vectors_are_consistent() {
+    let modulus = BigUint::from(101u64);
+    let vectors = test_vectors_with_rng(&modulus, 7, 32, &mut seeded_rng(3));
+    assert!(vectors.has_multiplicative_inverse);
+    for vector in &vectors.vectors {
+        assert_eq!((&vector.a_minus_b + &vector.b) % &modulus, vector.a);
+        assert_eq!(
+            (vector.a_div_b.as_ref().unwrap() * &vector.b) % &modulus,
+            vector.a
+        );
+        // half of the nonzero residues modulo 101 are squares
+        let is_square = (1..101u64).any(|x| BigUint::from((x * x) % 101) == vector.a);
+        match &vector.sqrt_a {
+            Some(root) => assert_eq!((root * root) % &modulus, vector.a),
+            None => assert!(!is_square && !vector.a.is_zero()),
+        }
+    }
+    assert_eq!(
+        vectors,
+        test_vectors_with_rng(&modulus, 7, 32, &mut seeded_rng(3))
+    );
+
+    // BN254's scalar field has p - 1 divisible by 2^28, which exercises the Tonelli-Shanks loop
+    let bn254_fr = crate::try_bignum_from_string(
+        "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+    )
+    .unwrap();
+    let vectors = test_vectors_with_rng(&bn254_fr, 254, 8, &mut seeded_rng(3));
+    assert!(vectors.vectors.iter().any(|vector| vector.sqrt_a.is_some()));
+    for vector in &vectors.vectors {
+        if let Some(root) = &vector.sqrt_a {
+            assert_eq!((root * root) % &bn254_fr, vector.a);
+        }
+    }
+
+    let uint_vectors = test_vectors(&(BigUint::one() << 256usize), 256, 2);
+    assert!(uint_vectors.vectors[0].a_div_b.is_none());
+    assert!(uint_vectors
+        .to_noir("U256")
+        .starts_with("// 2 test vectors for U256"));
+    assert!(!uint_vectors.to_noir("U256").contains("SQRT"));
+}
@@ -0,0 +1,73 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use wasm_bindgen::prelude::*;
+
+use crate::{bn_limbs, redc_limbs, try_bignum_from_string};
+
+/**
+ * @brief parse a modulus for the javascript bindings, which report errors instead of panicking
+ */
+fn parse_modulus(modulus: &str) -> Result<BigUint, JsError> {
+    let modulus = try_bignum_from_string(modulus).map_err(|error| JsError::new(&error))?;
+    if modulus.is_zero() {
+        return Err(JsError::new("modulus must be nonzero"));
+    }
+    Ok(modulus)
+}
+
+/**
+ * @brief javascript binding of `bn_instance_from_string`
+ */
+#[wasm_bindgen(js_name = bnInstanceFromString)]
+pub fn bn_instance_from_string(
+    modulus: &str,
+    name: String,
+    is_uint: bool,
+) -> Result<String, JsError> {
+    let modulus = parse_modulus(modulus)?;
+    crate::instance_in_lang(&modulus, name, is_uint, crate::Lang::Noir)
+        .map_err(|error| JsError::new(&error))
+}
+
+/**
+ * @brief javascript binding of `bn_limbs_from_string`
+ */
+#[wasm_bindgen(js_name = bnLimbsFromString)]
+pub fn bn_limbs_from_string(value: &str) -> Result<String, JsError> {
+    let value = try_bignum_from_string(value).map_err(|error| JsError::new(&error))?;
+    let num_bits = value.bits();
+    Ok(bn_limbs(value, num_bits))
+}
+
+/**
+ * @brief javascript binding of `redc_limbs_from_string`
+ */
+#[wasm_bindgen(js_name = redcLimbsFromString)]
+pub fn redc_limbs_from_string(modulus: &str) -> Result<String, JsError> {
+    let modulus = parse_modulus(modulus)?;
+    let num_bits = modulus.bits();
+    Ok(redc_limbs(modulus, num_bits))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn test_wasm_bindings() {
+    let bn254 = "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+    assert_eq!(
+        bn_instance_from_string(bn254, String::from("BN254_Fq"), false).unwrap(),
+        crate::bn_instance_from_string(String::from(bn254), String::from("BN254_Fq"), false)
+    );
+    assert_eq!(
+        bn_limbs_from_string("2^128").unwrap(),
+        crate::bn_limbs_from_string(String::from("2^128"))
+    );
+    assert_eq!(
+        redc_limbs_from_string(bn254).unwrap(),
+        crate::redc_limbs_from_string(String::from(bn254))
+    );
+    assert!(redc_limbs_from_string("0").is_err());
+    assert!(bn_instance_from_string("2^240", String::from("U240"), true)
+        .unwrap()
+        .contains("BigNumParams<3, 240>"));
+    assert!(bn_instance_from_string("2^255", String::from("mod"), true).is_err());
+}